@@ -4,6 +4,12 @@ use crate::container_structs::Container;
 use crate::container_create::CreateContainerResponseFromAPI;
 use crate::container_procceses::ContainerProcessesResponse;
 use crate::container_stats::Stats;
+use crate::container_changes::FilesystemChange;
+use crate::container_archive::PathStat;
+use crate::container_prune::PruneReport;
+use crate::container_wait::WaitResponse;
+use crate::container_run::RunOutput;
+use crate::exec_structs::{CreateExecResponseFromAPI, InspectedExec};
 use serde::Deserialize;
 
 //pub type NoImplementedYet = Bytes;
@@ -32,8 +38,21 @@ pub const GET_CONTAINER_LOGS_END: &str = "/logs";
 pub const GET_CONTAINER_LOGS_METHOD: Method = Method::GET;
 pub type GetContainerLogsReturn = String;
 
-//pub const GET_CHANGES_CONTAINER: &str = "/containers/{id}/changes";
-//pub const EXPORT_CONTAINER: &str = "/containers/{id}/export";
+pub const GET_CHANGES_CONTAINER_START: &str = "/containers/";
+pub const GET_CHANGES_CONTAINER_END: &str = "/changes";
+pub const GET_CHANGES_CONTAINER_METHOD: Method = Method::GET;
+pub type GetContainerChangesReturn = Vec<FilesystemChange>;
+
+pub const EXPORT_CONTAINER_START: &str = "/containers/";
+pub const EXPORT_CONTAINER_END: &str = "/export";
+pub const EXPORT_CONTAINER_METHOD: Method = Method::GET;
+
+pub const ARCHIVE_CONTAINER_START: &str = "/containers/";
+pub const ARCHIVE_CONTAINER_END: &str = "/archive";
+pub const GET_ARCHIVE_CONTAINER_METHOD: Method = Method::GET;
+pub const STAT_ARCHIVE_CONTAINER_METHOD: Method = Method::HEAD;
+pub const PUT_ARCHIVE_CONTAINER_METHOD: Method = Method::PUT;
+pub type ArchiveContainerReturn = PathStat;
 
 pub const GET_STATS_CONTAINER_START: &str = "/containers/";
 pub const GET_STATS_CONTAINER_END: &str = "/stats";
@@ -77,6 +96,7 @@ pub const UNPAUSE_CONTAINER_METHOD: Method = Method::POST;
 pub const WAIT_CONTAINER_START: &str = "/containers/";
 pub const WAIT_CONTAINER_END: &str = "/wait";
 pub const WAIT_CONTAINER_METHOD: Method = Method::POST;
+pub type WaitContainerReturn = WaitResponse;
 
 pub const REMOVE_CONTAINER: &str = "/containers/";
 pub const REMOVE_CONTAINER_METHOD: Method = Method::DELETE;
@@ -87,6 +107,35 @@ pub const RESIZE_CONTAINER_TTY_METHOD: Method = Method::POST;
 
 pub const DELETE_STOPPED_CONTAINERS: &str = "/containers/prune";
 pub const DELETE_STOPPED_CONTAINERS_METHOD: Method = Method::POST;
+pub type DeleteStoppedContainersReturn = PruneReport;
+
+pub type RunContainerReturn = RunOutput;
+
+pub const INSPECT_IMAGE_START: &str = "/images/";
+pub const INSPECT_IMAGE_END: &str = "/json";
+pub const INSPECT_IMAGE_METHOD: Method = Method::GET;
+
+pub const PULL_IMAGE: &str = "/images/create";
+pub const PULL_IMAGE_METHOD: Method = Method::POST;
+
+pub const CREATE_EXEC_START: &str = "/containers/";
+pub const CREATE_EXEC_END: &str = "/exec";
+pub const CREATE_EXEC_METHOD: Method = Method::POST;
+pub type CreateExecReturn = CreateExecResponseFromAPI;
+
+pub const START_EXEC_START: &str = "/exec/";
+pub const START_EXEC_END: &str = "/start";
+pub const START_EXEC_METHOD: Method = Method::POST;
+pub type StartExecReturn = Vec<u8>;
+
+pub const RESIZE_EXEC_START: &str = "/exec/";
+pub const RESIZE_EXEC_END: &str = "/resize";
+pub const RESIZE_EXEC_METHOD: Method = Method::POST;
+
+pub const INSPECT_EXEC_START: &str = "/exec/";
+pub const INSPECT_EXEC_END: &str = "/json";
+pub const INSPECT_EXEC_METHOD: Method = Method::GET;
+pub type InspectExecReturn = InspectedExec;
 
 #[derive(Deserialize, Debug, Clone, Default)]
 pub struct ErrorMessage {
@@ -1,14 +1,24 @@
+use std::time::{Duration, Instant};
+
 use hyper::{Client as HyperClient, Method};
 use hyperlocal::{UnixClientExt, UnixConnector};
+use regex::Regex;
 
-use crate::request::request;
-use crate::{containers_service::ContainersService};
+use crate::api::{EmptyOk, GET_CONTAINER_LOGS_START, GET_CONTAINER_LOGS_END, GET_CONTAINER_LOGS_METHOD};
+use crate::container_inspect::InspectedContainer;
+use crate::container_ready::{Condition, LineMatcher, probe_tcp, tcp_addresses};
+use crate::container_state::{ContainerState, HealthStatus};
+use crate::error::{err, invalid};
+use crate::request::{request, request_streamed_timeout};
+use crate::stream::Demultiplexer;
+use crate::{containers_service::{ContainersService, ContainersServiceTrait}, exec_service::{ExecService, ExecServiceTrait}};
 
 pub struct Client {
     pub url: String,
     pub client: HyperClient<UnixConnector>,
     pub runtime: tokio::runtime::Runtime,
     pub containers: ContainersService,
+    pub exec: ExecService,
 }
 
 pub trait ClientTrait {
@@ -16,6 +26,9 @@ pub trait ClientTrait {
     fn ping(&self) -> Result<(), Box<dyn std::error::Error>>;
 }
 
+const WAIT_UNTIL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const TCP_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl ClientTrait for Client {
     fn new(url: String) -> Self {
         let client = HyperClient::unix();
@@ -27,11 +40,18 @@ impl ClientTrait for Client {
             runtime: tokio::runtime::Runtime::new().unwrap(),
         };
 
+        let exec = ExecService {
+            url: url.clone(),
+            client: client.clone(),
+            runtime: tokio::runtime::Runtime::new().unwrap(),
+        };
+
         Client {
             url,
             client,
             runtime,
             containers,
+            exec,
         }
     }
 
@@ -42,12 +62,126 @@ impl ClientTrait for Client {
             Ok(r) => {
                 if r.status != 200 {
                     let err_message: serde_json::Value = serde_json::from_slice(&r.body)?;
-                    return Err(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Server error: {}", err_message))).into());
+                    return Err(Box::new(std::io::Error::other(format!("Server error: {}", err_message))).into());
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
     }
-}
\ No newline at end of file
+}
+
+impl Client {
+    /// Waits until a container is ready, instead of sleeping a fixed time after starting it.
+    ///
+    /// The container is inspected every 250ms until the condition holds, except for `LogMatches` which follows the logs of the container.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * condition - What the container should reach to be ready.
+    /// * timeout - How long to wait for the condition.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an empty value once the condition holds on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// The error is of kind `TimedOut` when the timeout elapses; waiting also fails as soon as the container exits or becomes unhealthy.
+    pub fn wait_until(&mut self, id: &str, condition: &Condition, timeout: Duration) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let deadline = Instant::now() + timeout;
+        if let Condition::LogMatches(regex) = condition {
+            return wait_for_log(self, id, regex, deadline);
+        }
+
+        loop {
+            let inspected = self.containers.inspect_container(id, false)?;
+            if is_ready(self, id, &inspected, condition, deadline)? {
+                return Ok(());
+            }
+
+            if matches!(inspected.state.status, ContainerState::Exited | ContainerState::Dead) {
+                return Err(Box::new(std::io::Error::other(format!("container exited with code {} before it was ready", inspected.state.exit_code))));
+            }
+
+            // The last check happens when the deadline is reached, not up to one interval before it
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "Timed out waiting for the container to be ready")));
+            }
+
+            std::thread::sleep(std::cmp::min(WAIT_UNTIL_POLL_INTERVAL, remaining));
+        }
+    }
+}
+
+fn is_ready(client: &mut Client, id: &str, inspected: &InspectedContainer, condition: &Condition, deadline: Instant) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let running = inspected.state.status == ContainerState::Running;
+    match condition {
+        Condition::Running => Ok(running),
+        Condition::Healthy => match &inspected.state.health {
+            None => Err(invalid(format!("container '{}' has no health check", id))),
+            Some(health) => match health.status {
+                HealthStatus::Healthy => Ok(true),
+                HealthStatus::Unhealthy => {
+                    let output = health.log.last().map(|entry| entry.output.trim().to_string()).unwrap_or_default();
+                    Err(Box::new(std::io::Error::other(format!("container is unhealthy: {}", output))))
+                },
+                _ => Ok(false),
+            },
+        },
+        Condition::TcpPort(port) => {
+            if !running {
+                return Ok(false);
+            }
+
+            for address in tcp_addresses(inspected, *port) {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                let timeout = std::cmp::min(remaining, TCP_CONNECT_TIMEOUT).max(Duration::from_millis(1));
+                if probe_tcp(&address, timeout) {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        },
+        Condition::ExecSucceeds(cmd) => {
+            if !running {
+                return Ok(false);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let output = client.exec.run_in_container(id, cmd.clone(), None, Some(remaining))?;
+            Ok(output.exit_code == 0)
+        },
+        Condition::LogMatches(_) => Ok(false),
+    }
+}
+
+fn wait_for_log(client: &mut Client, id: &str, regex: &Regex, deadline: Instant) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+    let inspected = client.containers.inspect_container(id, false)?;
+    let mut stdout = LineMatcher::new(regex);
+    let mut stderr = LineMatcher::new(regex);
+
+    // Following the logs returns every line already written, then the new ones until the container exits
+    let url = format!("{}{}{}?follow=true&stdout=true&stderr=true", GET_CONTAINER_LOGS_START, id, GET_CONTAINER_LOGS_END);
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let response = {
+        let mut demultiplexer = Demultiplexer::new(&mut stdout, &mut stderr, inspected.config.tty);
+        request_streamed_timeout(&client.client, client.url.clone(), url, GET_CONTAINER_LOGS_METHOD, 200, &mut demultiplexer, Some(remaining), &client.runtime)
+    };
+
+    if stdout.matched || stderr.matched {
+        return Ok(());
+    }
+
+    let response = response?;
+    if response.status != 200 {
+        return Err(err(&response.body)?);
+    }
+
+    if stdout.finish() || stderr.finish() {
+        return Ok(());
+    }
+
+    Err(Box::new(std::io::Error::other(format!("container exited before a log line matched '{}'", regex))))
+}
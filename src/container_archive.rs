@@ -0,0 +1,87 @@
+use serde::{Serialize, Deserialize};
+use std::{fs, io, path::{Component, Path}};
+use base64::Engine as _;
+
+/// Header containing the base64 encoded `PathStat` of the path requested to the archive endpoints.
+pub const PATH_STAT_HEADER: &str = "X-Docker-Container-Path-Stat";
+
+const MODE_DIR: u32 = 1 << 31;
+const MODE_SYMLINK: u32 = 1 << 27;
+
+/// PathStat is the information about a path inside a container filesystem
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathStat {
+    #[serde(rename = "name", default)]
+    pub name: String,
+    #[serde(rename = "size", default)]
+    pub size: i64,
+    /// File mode and permission bits, as a Go `os.FileMode`.
+    #[serde(rename = "mode", default)]
+    pub mode: u32,
+    #[serde(rename = "mtime", default)]
+    pub mtime: String,
+    #[serde(rename = "linkTarget", default)]
+    pub link_target: String,
+}
+
+impl PathStat {
+    /// Decodes the value of the `X-Docker-Container-Path-Stat` header.
+    pub fn from_header(value: &[u8]) -> Result<PathStat, Box<dyn std::error::Error + Send + Sync>> {
+        let json = base64::engine::general_purpose::STANDARD.decode(value)?;
+        let stat = serde_json::from_slice(&json)?;
+
+        Ok(stat)
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.mode & MODE_DIR != 0
+    }
+
+    pub fn is_symlink(&self) -> bool {
+        self.mode & MODE_SYMLINK != 0
+    }
+
+    /// The Unix permission bits of the path.
+    pub fn permissions(&self) -> u32 {
+        self.mode & 0o777
+    }
+}
+
+/// Builds a tar archive containing a host file or directory, named after the last component of `path`.
+pub fn archive_host_path(path: &Path) -> io::Result<Vec<u8>> {
+    let name = match path.file_name() {
+        Some(name) => name.to_os_string(),
+        None => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("cannot archive {}: the path has no file name", path.display()))),
+    };
+
+    let mut builder = tar::Builder::new(vec![]);
+    builder.follow_symlinks(false);
+    if fs::symlink_metadata(path)?.is_dir() {
+        builder.append_dir_all(&name, path)?;
+    } else {
+        builder.append_path_with_name(path, &name)?;
+    }
+
+    builder.into_inner()
+}
+
+/// Extracts a tar archive, as returned by the archive endpoint, into the host directory `dst`.
+///
+/// Entries that are absolute, contain `..` or would be written outside of `dst` through a symbolic link are rejected with an error
+/// of kind `InvalidData`, nothing is written for them.
+pub fn extract_archive<R: io::Read>(reader: R, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+
+        let escapes = path.components().any(|component| !matches!(component, Component::Normal(_) | Component::CurDir));
+        if escapes || !entry.unpack_in(dst)? {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("refusing to extract {} outside of {}", path.display(), dst.display())));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::api::EmptyOk;
+
+/// BulkResult is the outcome of a bulk operation for one container
+#[derive(Debug)]
+pub struct BulkResult {
+    pub id: String,
+    /// The first name of the container, without the leading `/`.
+    pub name: String,
+    pub result: Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>,
+}
+
+/// BulkReport is the outcome of a bulk operation for every selected container, in the order they were listed
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    pub results: Vec<BulkResult>,
+}
+
+impl BulkReport {
+    /// Returns true when the operation succeeded for every container, including when no container was selected.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(|result| result.result.is_ok())
+    }
+
+    /// Returns the containers the operation succeeded for.
+    pub fn succeeded(&self) -> Vec<&BulkResult> {
+        self.results.iter().filter(|result| result.result.is_ok()).collect()
+    }
+
+    /// Returns the containers the operation failed for.
+    pub fn failed(&self) -> Vec<&BulkResult> {
+        self.results.iter().filter(|result| result.result.is_err()).collect()
+    }
+}
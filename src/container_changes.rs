@@ -0,0 +1,42 @@
+use serde::{Serialize, Deserialize};
+
+/// ChangeKind is the kind of change made to a path of the container filesystem
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum ChangeKind {
+    Modified,
+    Added,
+    Deleted,
+}
+
+impl TryFrom<u8> for ChangeKind {
+    type Error = String;
+
+    fn try_from(kind: u8) -> Result<Self, Self::Error> {
+        match kind {
+            0 => Ok(ChangeKind::Modified),
+            1 => Ok(ChangeKind::Added),
+            2 => Ok(ChangeKind::Deleted),
+            _ => Err(format!("unknown filesystem change kind: {}", kind)),
+        }
+    }
+}
+
+impl From<ChangeKind> for u8 {
+    fn from(kind: ChangeKind) -> Self {
+        match kind {
+            ChangeKind::Modified => 0,
+            ChangeKind::Added => 1,
+            ChangeKind::Deleted => 2,
+        }
+    }
+}
+
+/// FilesystemChange is a path changed in the writable layer of a container
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FilesystemChange {
+    #[serde(rename = "Path")]
+    pub path: String,
+    #[serde(rename = "Kind")]
+    pub kind: ChangeKind,
+}
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use serde::{Serialize, Deserialize};
-use crate::container_network::{HostConfig, NetworkingConfig};
+use crate::{container_host_config::HostConfig, container_network::NetworkingConfig, container_health::HealthConfig, signal::Signal};
 
 /// CreateContainerFrom is the struct that is used to create a container
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct CreateContainerFrom {
     #[serde(rename = "Hostname", skip_serializing_if = "Option::is_none", default)]
     pub hostname: Option<String>,
@@ -27,8 +27,13 @@ pub struct CreateContainerFrom {
     pub env: Option<Vec<String>>,
     #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none", default)]
     pub cmd: Option<Vec<String>>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none", default)]
+    pub healthcheck: Option<HealthConfig>,
+    /// Command is already escaped (Windows only).
+    #[serde(rename = "ArgsEscaped", skip_serializing_if = "Option::is_none", default)]
+    pub args_escaped: Option<bool>,
     #[serde(rename = "Entrypoint", skip_serializing_if = "Option::is_none", default)]
-    pub entrypoint: Option<String>,
+    pub entrypoint: Option<Vec<String>>,
     #[serde(rename = "Image", skip_serializing_if = "Option::is_none", default)]
     pub image: Option<String>,
     #[serde(rename = "Labels", skip_serializing_if = "Option::is_none", default)]
@@ -43,47 +48,23 @@ pub struct CreateContainerFrom {
     pub mac_address: Option<String>,
     #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none", default)]
     pub exposed_ports: Option<HashMap<String, ()>>,
+    /// `ONBUILD` metadata that were defined in the image's `Dockerfile`.
+    #[serde(rename = "OnBuild", skip_serializing_if = "Option::is_none", default)]
+    pub on_build: Option<Vec<String>>,
     #[serde(rename = "StopSignal", skip_serializing_if = "Option::is_none", default)]
-    pub stop_signal: Option<String>,
+    pub stop_signal: Option<Signal>,
     #[serde(rename = "StopTimeout", skip_serializing_if = "Option::is_none", default)]
     pub stop_timeout: Option<i32>,
+    /// Shell for when `RUN`, `CMD`, and `ENTRYPOINT` uses a shell.
+    #[serde(rename = "Shell", skip_serializing_if = "Option::is_none", default)]
+    pub shell: Option<Vec<String>>,
     #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none", default)]
     pub host_config: Option<HostConfig>,
     #[serde(rename = "NetworkingConfig", skip_serializing_if = "Option::is_none", default)]
     pub networking_config: Option<NetworkingConfig>,
 }
 
-impl Default for CreateContainerFrom {
-    fn default() -> Self {
-        CreateContainerFrom {
-            hostname: None,
-            domainname: None,
-            user: None,
-            attach_stdin: None,
-            attach_stdout: None,
-            attach_stderr: None,
-            tty: None,
-            open_stdin: None,
-            stdin_once: None,
-            env: None,
-            cmd: None,
-            entrypoint: None,
-            image: None,
-            labels: None,
-            volumes: None,
-            working_dir: None,
-            network_disabled: None,
-            mac_address: None,
-            exposed_ports: None,
-            stop_signal: None,
-            stop_timeout: None,
-            host_config: None,
-            networking_config: None,
-        }
-    }
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 pub struct CreateContainerResponseFromAPI {
     #[serde(rename = "Id", default)]
     pub id: String,
@@ -91,15 +72,6 @@ pub struct CreateContainerResponseFromAPI {
     pub warnings: Vec<String>,
 }
 
-impl Default for CreateContainerResponseFromAPI {
-    fn default() -> Self {
-        CreateContainerResponseFromAPI {
-            id: String::new(),
-            warnings: Vec::new(),
-        }
-    }
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EmptyMap {}
 
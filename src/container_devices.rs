@@ -0,0 +1,103 @@
+use std::{fmt, str::FromStr};
+
+use crate::{container_structs::GenericDevice, error::invalid};
+
+/// Returns true for a combination of `r`, `w` and `m`, each at most once, as `docker run --device` accepts.
+fn is_device_mode(permissions: &str) -> bool {
+    is_cgroup_permissions(permissions) && permissions.chars().enumerate().all(|(i, c)| !permissions[..i].contains(c))
+}
+
+/// Returns true for one to three of `r`, `w` and `m`, the Engine accepting the same letter twice in a device cgroup rule.
+fn is_cgroup_permissions(permissions: &str) -> bool {
+    (1..=3).contains(&permissions.len()) && permissions.chars().all(|c| "rwm".contains(c))
+}
+
+impl FromStr for GenericDevice {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    /// Parses a device in `docker run --device` notation, `host path[:container path][:permissions]`.
+    ///
+    /// The device is mapped to the same path in the container when no container path is given, with the `rwm` permissions when none are given.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (host_path, container_path, cgroup_permissions) = match parts.as_slice() {
+            [host_path] => (*host_path, *host_path, "rwm"),
+            [host_path, permissions] if is_device_mode(permissions) => (*host_path, *host_path, *permissions),
+            [host_path, container_path] => (*host_path, *container_path, "rwm"),
+            [host_path, container_path, permissions] => (*host_path, *container_path, *permissions),
+            _ => return Err(invalid(format!("invalid device specification: '{}'", value))),
+        };
+
+        let device = GenericDevice {
+            host_path: host_path.to_string(),
+            container_path: container_path.to_string(),
+            cgroup_permissions: cgroup_permissions.to_string(),
+        };
+        device.validate()?;
+        Ok(device)
+    }
+}
+
+impl fmt::Display for GenericDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.host_path, self.container_path, self.cgroup_permissions)
+    }
+}
+
+impl GenericDevice {
+    /// Checks the paths are absolute and the permissions are a combination of `r`, `w` and `m`, as `docker run --device` does.
+    ///
+    /// Devices of Windows containers, e.g. `class/<GUID>`, do not pass, `validate_devices` leaves the paths to the Engine.
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if !self.host_path.starts_with('/') {
+            return Err(invalid(format!("the device path on the host must be absolute: '{}'", self.host_path)));
+        }
+        if !self.container_path.starts_with('/') {
+            return Err(invalid(format!("the device path in the container must be absolute: '{}'", self.container_path)));
+        }
+        if !is_device_mode(&self.cgroup_permissions) {
+            return Err(invalid(format!("invalid device permissions '{}', expected a combination of 'r', 'w' and 'm'", self.cgroup_permissions)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks a device cgroup rule, `type major:minor permissions`, e.g. `c 1:3 mr` or `b 8:* rwm`.
+///
+/// The type is `a` (all), `c` (char) or `b` (block), the major and minor numbers are integers or `*`,
+/// the same grammar as the Engine, `^([acb]) ([0-9]+|\*):([0-9]+|\*) ([rwm]{1,3})$`.
+pub fn validate_device_cgroup_rule(rule: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let error = || invalid(format!("invalid device cgroup rule: '{}'", rule));
+    let is_number = |number: &str| number == "*" || (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+
+    let parts: Vec<&str> = rule.split(' ').collect();
+    let (device_type, numbers, permissions) = match parts.as_slice() {
+        [device_type, numbers, permissions] => (*device_type, *numbers, *permissions),
+        _ => return Err(error()),
+    };
+    let (major, minor) = numbers.split_once(':').ok_or_else(error)?;
+
+    if !["a", "c", "b"].contains(&device_type) || !is_number(major) || !is_number(minor) || !is_cgroup_permissions(permissions) {
+        return Err(error());
+    }
+
+    Ok(())
+}
+
+/// Checks the devices and the device cgroup rules of a new container or of an update, before they are sent to the Engine.
+///
+/// The paths of the devices are left to the Engine, which knows the platform of the container,
+/// only the permissions are checked when they are set.
+pub fn validate_devices(devices: &[GenericDevice], device_cgroup_rules: &[String]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    for device in devices {
+        if !device.cgroup_permissions.is_empty() && !is_device_mode(&device.cgroup_permissions) {
+            return Err(invalid(format!("invalid device permissions '{}', expected a combination of 'r', 'w' and 'm'", device.cgroup_permissions)));
+        }
+    }
+    for rule in device_cgroup_rules {
+        validate_device_cgroup_rule(rule)?;
+    }
+
+    Ok(())
+}
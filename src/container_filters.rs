@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::container_state::{ContainerState, HealthStatus};
+
+/// ContainerFilters selects the containers returned by `list_containers` and acted on by the bulk operations
+///
+/// Every non empty field narrows the selection; values of the same field are alternatives, except `labels` which must all match.
+/// The bulk operations reject empty filters, use `ContainerFilters::all()` to act on every container.
+#[derive(Debug, Clone, Default)]
+pub struct ContainerFilters {
+    /// Containers created from these images, or images descending from them, as `name[:tag]`, an image id or a digest.
+    pub ancestors: Vec<String>,
+    /// Containers created before this container, as an id or a name.
+    pub before: Option<String>,
+    /// Containers created after this container, as an id or a name.
+    pub since: Option<String>,
+    /// Containers exposing these ports, as `port[/protocol]` or `startport-endport[/protocol]`.
+    pub expose: Vec<String>,
+    /// Containers publishing these ports, as `port[/protocol]` or `startport-endport[/protocol]`.
+    pub publish: Vec<String>,
+    /// Exited containers with these exit codes.
+    pub exited: Vec<i64>,
+    pub health: Vec<HealthStatus>,
+    /// Containers whose id starts with one of these.
+    pub ids: Vec<String>,
+    /// Containers with all these labels, as `key` or `key=value`.
+    pub labels: Vec<String>,
+    /// Containers whose name matches one of these regular expressions.
+    pub names: Vec<String>,
+    /// Containers connected to these networks, as ids or names.
+    pub networks: Vec<String>,
+    pub status: Vec<ContainerState>,
+    /// Containers using these volumes or mounting these paths.
+    pub volumes: Vec<String>,
+    /// Lets the bulk operations act on every container when no other field is set, it does not change what `list_containers` returns.
+    pub match_all: bool,
+}
+
+impl ContainerFilters {
+    /// Returns filters selecting every container, including for the bulk operations.
+    pub fn all() -> Self {
+        ContainerFilters { match_all: true, ..Default::default() }
+    }
+
+    /// Returns true when no field narrows the selection, `match_all` aside.
+    pub fn is_empty(&self) -> bool {
+        self.to_map().is_empty()
+    }
+
+    /// Encodes the filters as the JSON map expected by the `filters` query parameter.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.to_map())
+    }
+
+    fn to_map(&self) -> HashMap<&'static str, Vec<String>> {
+        let mut filters: HashMap<&'static str, Vec<String>> = HashMap::new();
+        let mut insert = |name, values: Vec<String>| {
+            if !values.is_empty() {
+                filters.insert(name, values);
+            }
+        };
+
+        insert("ancestor", self.ancestors.clone());
+        insert("before", self.before.iter().cloned().collect());
+        insert("since", self.since.iter().cloned().collect());
+        insert("expose", self.expose.clone());
+        insert("publish", self.publish.clone());
+        insert("exited", self.exited.iter().map(|code| code.to_string()).collect());
+        insert("health", self.health.iter().map(|health| health.to_string()).collect());
+        insert("id", self.ids.clone());
+        insert("label", self.labels.clone());
+        insert("name", self.names.clone());
+        insert("network", self.networks.clone());
+        insert("status", self.status.iter().map(|status| status.to_string()).collect());
+        insert("volume", self.volumes.clone());
+
+        filters
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Serialize, Deserialize};
+use std::time::Duration;
+
+use crate::error::invalid;
+
+/// HealthTest is the test performed to check that a container is healthy
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub enum HealthTest {
+    /// Inherit the health check of the image.
+    #[default]
+    Inherit,
+    /// Disable the health check, including the one of the image.
+    None,
+    /// Run the command directly, as `["CMD", args...]`.
+    Cmd(Vec<String>),
+    /// Run the command with the shell of the container, as `["CMD-SHELL", command]`.
+    CmdShell(String),
+}
+
+impl TryFrom<Vec<String>> for HealthTest {
+    type Error = String;
+
+    fn try_from(test: Vec<String>) -> Result<Self, Self::Error> {
+        let (kind, args) = match test.split_first() {
+            Some((kind, args)) => (kind.as_str(), args),
+            None => return Ok(HealthTest::Inherit),
+        };
+
+        match kind {
+            "NONE" => Ok(HealthTest::None),
+            "CMD" => Ok(HealthTest::Cmd(args.to_vec())),
+            "CMD-SHELL" => Ok(HealthTest::CmdShell(args.join(" "))),
+            _ => Err(format!("invalid health check test: '{}'", kind)),
+        }
+    }
+}
+
+impl From<HealthTest> for Vec<String> {
+    fn from(test: HealthTest) -> Self {
+        match test {
+            HealthTest::Inherit => vec![],
+            HealthTest::None => vec!["NONE".to_string()],
+            HealthTest::Cmd(args) => std::iter::once("CMD".to_string()).chain(args).collect(),
+            HealthTest::CmdShell(command) => vec!["CMD-SHELL".to_string(), command],
+        }
+    }
+}
+
+/// HealthConfig is the health check of a container
+///
+/// Durations that are not set, or zero, are inherited from the image or the Engine defaults.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct HealthConfig {
+    #[serde(rename = "Test", skip_serializing_if = "Option::is_none", default)]
+    pub test: Option<HealthTest>,
+    /// The time to wait between checks.
+    #[serde(rename = "Interval", skip_serializing_if = "Option::is_none", with = "nanoseconds", default)]
+    pub interval: Option<Duration>,
+    /// The time to wait before considering the check to have hung.
+    #[serde(rename = "Timeout", skip_serializing_if = "Option::is_none", with = "nanoseconds", default)]
+    pub timeout: Option<Duration>,
+    /// The number of consecutive failures needed to consider a container as unhealthy.
+    #[serde(rename = "Retries", skip_serializing_if = "Option::is_none", default)]
+    pub retries: Option<u32>,
+    /// Start period for the container to initialize before the retries starts to count down.
+    #[serde(rename = "StartPeriod", skip_serializing_if = "Option::is_none", with = "nanoseconds", default)]
+    pub start_period: Option<Duration>,
+}
+
+impl HealthConfig {
+    /// Checks the durations are accepted by the Engine, which requires them to be zero or at least one millisecond.
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let durations = [("interval", self.interval), ("timeout", self.timeout), ("start period", self.start_period)];
+        for (name, duration) in durations {
+            if let Some(duration) = duration {
+                if !duration.is_zero() && duration < Duration::from_millis(1) {
+                    return Err(invalid(format!("health check {} must be at least 1ms", name)));
+                }
+
+                if duration.as_nanos() > i64::MAX as u128 {
+                    return Err(invalid(format!("health check {} is too long", name)));
+                }
+            }
+        }
+
+        if let Some(HealthTest::Cmd(args)) = &self.test {
+            if args.is_empty() {
+                return Err(invalid("health check CMD test requires a command".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Durations are sent to the Engine as an integer number of nanoseconds
+mod nanoseconds {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_i64(duration.as_nanos().min(i64::MAX as u128) as i64),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+        let nanoseconds: Option<i64> = Option::deserialize(deserializer)?;
+        Ok(nanoseconds.map(|nanoseconds| Duration::from_nanos(nanoseconds.max(0) as u64)))
+    }
+}
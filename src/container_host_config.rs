@@ -0,0 +1,431 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use crate::{container_structs::GenericDevice, container_inspect::DeviceRequest, container_update::{ThrottleDeviceWeight, ThrottleDeviceRate}};
+
+/// HostConfig is the host-specific configuration used to create a container
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HostConfig {
+    /// An integer value representing this container's relative CPU weight versus other containers.
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_shares: Option<i64>,
+
+    /// Memory limit (in bytes).
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none", default)]
+    pub memory: Option<i64>,
+
+    /// Path to cgroups under which the container's cgroup is created.
+    #[serde(rename = "CgroupParent", skip_serializing_if = "Option::is_none", default)]
+    pub cgroup_parent: Option<String>,
+
+    /// Block IO weight (relative weight) accepts a weight value between 0 and 1000.
+    #[serde(rename = "BlkioWeight", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_weight: Option<u16>,
+
+    /// Block IO weight (relative device weight) as `ThrottleDeviceWeight`
+    #[serde(rename = "BlkioWeightDevice", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_weight_device: Option<Vec<ThrottleDeviceWeight>>,
+
+    /// Block IO read rate limit (bytes per second) as `ThrottleDeviceRate`
+    #[serde(rename = "BlkioDeviceReadBps", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_device_read_bps: Option<Vec<ThrottleDeviceRate>>,
+
+    /// Block IO write rate limit (bytes per second) as `ThrottleDeviceRate`
+    #[serde(rename = "BlkioDeviceWriteBps", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_device_write_bps: Option<Vec<ThrottleDeviceRate>>,
+
+    /// Block IO read rate limit (IO per second) as `ThrottleDeviceRate`
+    #[serde(rename = "BlkioDeviceReadIOps", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_device_read_iops: Option<Vec<ThrottleDeviceRate>>,
+
+    /// Block IO write rate limit (IO per second) as `ThrottleDeviceRate`
+    #[serde(rename = "BlkioDeviceWriteIOps", skip_serializing_if = "Option::is_none", default)]
+    pub blkio_device_write_iops: Option<Vec<ThrottleDeviceRate>>,
+
+    /// The length of a CPU period in microseconds.
+    #[serde(rename = "CpuPeriod", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_period: Option<i64>,
+
+    /// Microseconds of CPU time that the container can get in a CPU period.
+    #[serde(rename = "CpuQuota", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_quota: Option<i64>,
+
+    /// CPU real-time period in microseconds.
+    #[serde(rename = "CpuRealtimePeriod", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_realtime_period: Option<i64>,
+
+    /// CPU real-time runtime in microseconds.
+    #[serde(rename = "CpuRealtimeRuntime", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_realtime_runtime: Option<i64>,
+
+    /// CPUs in which to allow execution (0-3, 0,1).
+    #[serde(rename = "CpusetCpus", skip_serializing_if = "Option::is_none", default)]
+    pub cpuset_cpus: Option<String>,
+
+    /// Memory nodes (MEMs) in which to allow execution (0-3, 0,1). Only effective on NUMA systems.
+    #[serde(rename = "CpusetMems", skip_serializing_if = "Option::is_none", default)]
+    pub cpuset_mems: Option<String>,
+
+    /// A list of devices to add to the container.
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none", default)]
+    pub devices: Option<Vec<GenericDevice>>,
+
+    /// A list of cgroup rules to apply to the container.
+    #[serde(rename = "DeviceCgroupRules", skip_serializing_if = "Option::is_none", default)]
+    pub device_cgroup_rules: Option<Vec<String>>,
+
+    /// A list of requests for devices to be sent to device drivers.
+    #[serde(rename = "DeviceRequests", skip_serializing_if = "Option::is_none", default)]
+    pub device_requests: Option<Vec<DeviceRequest>>,
+
+    /// Kernel memory limit (in bytes), deprecated by the Engine and only reported by API versions before 1.42.
+    #[serde(rename = "KernelMemory", skip_serializing_if = "Option::is_none", default)]
+    pub kernel_memory: Option<i64>,
+
+    /// Hard limit for kernel TCP buffer memory (in bytes).
+    #[serde(rename = "KernelMemoryTCP", skip_serializing_if = "Option::is_none", default)]
+    pub kernel_memory_tcp: Option<i64>,
+
+    /// Memory soft limit (in bytes).
+    #[serde(rename = "MemoryReservation", skip_serializing_if = "Option::is_none", default)]
+    pub memory_reservation: Option<i64>,
+
+    /// Total memory limit (memory + swap). Set as `-1` to enable unlimited swap.
+    #[serde(rename = "MemorySwap", skip_serializing_if = "Option::is_none", default)]
+    pub memory_swap: Option<i64>,
+
+    /// Tune a container's memory swappiness behavior. Accepts an integer between 0 and 100.
+    #[serde(rename = "MemorySwappiness", skip_serializing_if = "Option::is_none", default)]
+    pub memory_swappiness: Option<i64>,
+
+    /// CPU quota in units of 10^-9 CPUs.
+    #[serde(rename = "NanoCpus", skip_serializing_if = "Option::is_none", default)]
+    pub nano_cpus: Option<i64>,
+
+    /// Disable OOM Killer for the container.
+    #[serde(rename = "OomKillDisable", skip_serializing_if = "Option::is_none", default)]
+    pub oom_kill_disable: Option<bool>,
+
+    /// Run an init inside the container that forwards signals and reaps processes.
+    #[serde(rename = "Init", skip_serializing_if = "Option::is_none", default)]
+    pub init: Option<bool>,
+
+    /// Tune a container's PIDs limit. Set `0` or `-1` for unlimited.
+    #[serde(rename = "PidsLimit", skip_serializing_if = "Option::is_none", default)]
+    pub pids_limit: Option<i64>,
+
+    /// A list of resource limits to set in the container.
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none", default)]
+    pub ulimits: Option<Vec<Ulimit>>,
+
+    /// The number of usable CPUs (Windows only).
+    #[serde(rename = "CpuCount", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_count: Option<i64>,
+
+    /// The usable percentage of the available CPUs (Windows only).
+    #[serde(rename = "CpuPercent", skip_serializing_if = "Option::is_none", default)]
+    pub cpu_percent: Option<i64>,
+
+    /// Maximum IOps for the container system drive (Windows only).
+    #[serde(rename = "IOMaximumIOps", skip_serializing_if = "Option::is_none", default)]
+    pub io_maximum_iops: Option<u64>,
+
+    /// Maximum IO in bytes per second for the container system drive (Windows only).
+    #[serde(rename = "IOMaximumBandwidth", skip_serializing_if = "Option::is_none", default)]
+    pub io_maximum_bandwidth: Option<u64>,
+
+    /// A list of volume bindings for this container, as `host-src:container-dest[:options]`.
+    #[serde(rename = "Binds", skip_serializing_if = "Option::is_none", default)]
+    pub binds: Option<Vec<String>>,
+
+    /// Path to a file where the container ID is written.
+    #[serde(rename = "ContainerIDFile", skip_serializing_if = "Option::is_none", default)]
+    pub container_id_file: Option<String>,
+
+    /// The logging configuration for this container.
+    #[serde(rename = "LogConfig", skip_serializing_if = "Option::is_none", default)]
+    pub log_config: Option<LogConfig>,
+
+    /// Network mode to use for this container: `bridge`, `host`, `none`, `container:<name|id>` or the name of a network.
+    #[serde(rename = "NetworkMode", skip_serializing_if = "Option::is_none", default)]
+    pub network_mode: Option<String>,
+
+    /// A map of exposed container ports (`80/tcp`) and the host ports they are published to.
+    #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none", default)]
+    pub port_bindings: Option<HashMap<String, Vec<PortBinding>>>,
+
+    /// The behavior to apply when the container exits.
+    #[serde(rename = "RestartPolicy", skip_serializing_if = "Option::is_none", default)]
+    pub restart_policy: Option<RestartPolicy>,
+
+    /// Automatically remove the container when its process exits.
+    #[serde(rename = "AutoRemove", skip_serializing_if = "Option::is_none", default)]
+    pub auto_remove: Option<bool>,
+
+    /// Driver that this container uses to mount volumes.
+    #[serde(rename = "VolumeDriver", skip_serializing_if = "Option::is_none", default)]
+    pub volume_driver: Option<String>,
+
+    /// A list of volumes to inherit from another container, as `container name[:ro|rw]`.
+    #[serde(rename = "VolumesFrom", skip_serializing_if = "Option::is_none", default)]
+    pub volumes_from: Option<Vec<String>>,
+
+    /// Specification for mounts to be added to the container.
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none", default)]
+    pub mounts: Option<Vec<MountSpec>>,
+
+    /// Initial console size, as an `[height, width]` array.
+    #[serde(rename = "ConsoleSize", skip_serializing_if = "Option::is_none", default)]
+    pub console_size: Option<Vec<u16>>,
+
+    /// A list of kernel capabilities to add to the container.
+    #[serde(rename = "CapAdd", skip_serializing_if = "Option::is_none", default)]
+    pub cap_add: Option<Vec<String>>,
+
+    /// A list of kernel capabilities to drop from the container.
+    #[serde(rename = "CapDrop", skip_serializing_if = "Option::is_none", default)]
+    pub cap_drop: Option<Vec<String>>,
+
+    /// Cgroup namespace mode for the container: `private` or `host`.
+    #[serde(rename = "CgroupnsMode", skip_serializing_if = "Option::is_none", default)]
+    pub cgroupns_mode: Option<String>,
+
+    /// A list of DNS servers for the container to use.
+    #[serde(rename = "Dns", skip_serializing_if = "Option::is_none", default)]
+    pub dns: Option<Vec<String>>,
+
+    /// A list of DNS options.
+    #[serde(rename = "DnsOptions", skip_serializing_if = "Option::is_none", default)]
+    pub dns_options: Option<Vec<String>>,
+
+    /// A list of DNS search domains.
+    #[serde(rename = "DnsSearch", skip_serializing_if = "Option::is_none", default)]
+    pub dns_search: Option<Vec<String>>,
+
+    /// A list of hostnames/IP mappings to add to the container's `/etc/hosts` file, as `hostname:IP`.
+    #[serde(rename = "ExtraHosts", skip_serializing_if = "Option::is_none", default)]
+    pub extra_hosts: Option<Vec<String>>,
+
+    /// A list of additional groups that the container process will run as.
+    #[serde(rename = "GroupAdd", skip_serializing_if = "Option::is_none", default)]
+    pub group_add: Option<Vec<String>>,
+
+    /// IPC sharing mode for the container: `none`, `private`, `shareable`, `container:<name|id>` or `host`.
+    #[serde(rename = "IpcMode", skip_serializing_if = "Option::is_none", default)]
+    pub ipc_mode: Option<String>,
+
+    /// Cgroup to use for the container.
+    #[serde(rename = "Cgroup", skip_serializing_if = "Option::is_none", default)]
+    pub cgroup: Option<String>,
+
+    /// A list of links for the container, as `container_name:alias`.
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none", default)]
+    pub links: Option<Vec<String>>,
+
+    /// An integer value containing the score given to the container in order to tune OOM killer preferences.
+    #[serde(rename = "OomScoreAdj", skip_serializing_if = "Option::is_none", default)]
+    pub oom_score_adj: Option<i64>,
+
+    /// Set the PID (Process) Namespace mode for the container: `container:<name|id>` or `host`.
+    #[serde(rename = "PidMode", skip_serializing_if = "Option::is_none", default)]
+    pub pid_mode: Option<String>,
+
+    /// Gives the container full access to the host.
+    #[serde(rename = "Privileged", skip_serializing_if = "Option::is_none", default)]
+    pub privileged: Option<bool>,
+
+    /// Allocates an ephemeral host port for all of a container's exposed ports.
+    #[serde(rename = "PublishAllPorts", skip_serializing_if = "Option::is_none", default)]
+    pub publish_all_ports: Option<bool>,
+
+    /// Mount the container's root filesystem as read only.
+    #[serde(rename = "ReadonlyRootfs", skip_serializing_if = "Option::is_none", default)]
+    pub readonly_rootfs: Option<bool>,
+
+    /// A list of string values to customize labels for MLS systems, such as SELinux.
+    #[serde(rename = "SecurityOpt", skip_serializing_if = "Option::is_none", default)]
+    pub security_opt: Option<Vec<String>>,
+
+    /// Storage driver options for this container.
+    #[serde(rename = "StorageOpt", skip_serializing_if = "Option::is_none", default)]
+    pub storage_opt: Option<HashMap<String, String>>,
+
+    /// A map of container directories which should be replaced by tmpfs mounts, and their corresponding mount options.
+    #[serde(rename = "Tmpfs", skip_serializing_if = "Option::is_none", default)]
+    pub tmpfs: Option<HashMap<String, String>>,
+
+    /// UTS namespace to use for the container.
+    #[serde(rename = "UTSMode", skip_serializing_if = "Option::is_none", default)]
+    pub uts_mode: Option<String>,
+
+    /// Sets the usernamespace mode for the container when usernamespace remapping option is enabled.
+    #[serde(rename = "UsernsMode", skip_serializing_if = "Option::is_none", default)]
+    pub userns_mode: Option<String>,
+
+    /// Size of `/dev/shm` in bytes.
+    #[serde(rename = "ShmSize", skip_serializing_if = "Option::is_none", default)]
+    pub shm_size: Option<i64>,
+
+    /// A list of kernel parameters (sysctls) to set in the container.
+    #[serde(rename = "Sysctls", skip_serializing_if = "Option::is_none", default)]
+    pub sysctls: Option<HashMap<String, String>>,
+
+    /// Arbitrary non-identifying metadata attached to the container and passed to the runtime (API 1.43+).
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none", default)]
+    pub annotations: Option<HashMap<String, String>>,
+
+    /// Runtime to use with this container.
+    #[serde(rename = "Runtime", skip_serializing_if = "Option::is_none", default)]
+    pub runtime: Option<String>,
+
+    /// Isolation technology of the container (Windows only).
+    #[serde(rename = "Isolation", skip_serializing_if = "Option::is_none", default)]
+    pub isolation: Option<String>,
+
+    /// The list of paths to be masked inside the container (this overrides the default set of paths).
+    #[serde(rename = "MaskedPaths", skip_serializing_if = "Option::is_none", default)]
+    pub masked_paths: Option<Vec<String>>,
+
+    /// The list of paths to be set as read-only inside the container (this overrides the default set of paths).
+    #[serde(rename = "ReadonlyPaths", skip_serializing_if = "Option::is_none", default)]
+    pub readonly_paths: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Ulimit {
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "Soft", default)]
+    pub soft: i64,
+    #[serde(rename = "Hard", default)]
+    pub hard: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LogConfig {
+    /// The logging driver, e.g. `json-file`, `syslog`, `journald`, `none`.
+    #[serde(rename = "Type", default)]
+    pub _type: String,
+    #[serde(rename = "Config", skip_serializing_if = "HashMap::is_empty", default)]
+    pub config: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortBinding {
+    /// Host IP address that the container's port is mapped to, all interfaces when empty.
+    #[serde(rename = "HostIp", default)]
+    pub host_ip: String,
+    /// Host port number that the container's port is mapped to, a random port when empty.
+    #[serde(rename = "HostPort", default)]
+    pub host_port: String,
+}
+
+/// RestartPolicy is the behavior to apply when a container exits
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(from = "RestartPolicyConfig", into = "RestartPolicyConfig")]
+pub enum RestartPolicy {
+    /// Do not automatically restart.
+    #[default]
+    No,
+    /// Always restart.
+    Always,
+    /// Restart always except when the user has manually stopped the container.
+    UnlessStopped,
+    /// Restart only when the container exit code is non-zero, at most the given number of times (0 for no limit).
+    OnFailure(i64),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct RestartPolicyConfig {
+    #[serde(rename = "Name", default)]
+    name: String,
+    #[serde(rename = "MaximumRetryCount", default)]
+    maximum_retry_count: i64,
+}
+
+impl From<RestartPolicyConfig> for RestartPolicy {
+    fn from(config: RestartPolicyConfig) -> Self {
+        match config.name.as_str() {
+            "always" => RestartPolicy::Always,
+            "unless-stopped" => RestartPolicy::UnlessStopped,
+            "on-failure" => RestartPolicy::OnFailure(config.maximum_retry_count),
+            _ => RestartPolicy::No,
+        }
+    }
+}
+
+impl From<RestartPolicy> for RestartPolicyConfig {
+    fn from(policy: RestartPolicy) -> Self {
+        let (name, maximum_retry_count) = match policy {
+            RestartPolicy::No => ("no", 0),
+            RestartPolicy::Always => ("always", 0),
+            RestartPolicy::UnlessStopped => ("unless-stopped", 0),
+            RestartPolicy::OnFailure(count) => ("on-failure", count),
+        };
+
+        RestartPolicyConfig { name: name.to_string(), maximum_retry_count }
+    }
+}
+
+/// MountSpec is a mount to be added to a container at creation
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MountSpec {
+    /// Container path.
+    #[serde(rename = "Target", default)]
+    pub target: String,
+    /// Mount source, e.g. a volume name or a host path.
+    #[serde(rename = "Source", skip_serializing_if = "String::is_empty", default)]
+    pub source: String,
+    /// The mount type: `bind`, `volume`, `tmpfs` or `npipe`.
+    #[serde(rename = "Type", default)]
+    pub _type: String,
+    #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none", default)]
+    pub read_only: Option<bool>,
+    /// The consistency requirement for the mount: `default`, `consistent`, `cached`, or `delegated`.
+    #[serde(rename = "Consistency", skip_serializing_if = "Option::is_none", default)]
+    pub consistency: Option<String>,
+    #[serde(rename = "BindOptions", skip_serializing_if = "Option::is_none", default)]
+    pub bind_options: Option<BindOptions>,
+    #[serde(rename = "VolumeOptions", skip_serializing_if = "Option::is_none", default)]
+    pub volume_options: Option<VolumeOptions>,
+    #[serde(rename = "TmpfsOptions", skip_serializing_if = "Option::is_none", default)]
+    pub tmpfs_options: Option<TmpfsOptions>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BindOptions {
+    /// A propagation mode: `private`, `rprivate`, `shared`, `rshared`, `slave` or `rslave`.
+    #[serde(rename = "Propagation", skip_serializing_if = "Option::is_none", default)]
+    pub propagation: Option<String>,
+    #[serde(rename = "NonRecursive", skip_serializing_if = "Option::is_none", default)]
+    pub non_recursive: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VolumeOptions {
+    /// Populate the volume with data from the target.
+    #[serde(rename = "NoCopy", skip_serializing_if = "Option::is_none", default)]
+    pub no_copy: Option<bool>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none", default)]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(rename = "DriverConfig", skip_serializing_if = "Option::is_none", default)]
+    pub driver_config: Option<VolumeDriverConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct VolumeDriverConfig {
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "Options", skip_serializing_if = "HashMap::is_empty", default)]
+    pub options: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TmpfsOptions {
+    /// The size for the tmpfs mount in bytes.
+    #[serde(rename = "SizeBytes", skip_serializing_if = "Option::is_none", default)]
+    pub size_bytes: Option<i64>,
+    /// The permission mode for the tmpfs mount in an integer.
+    #[serde(rename = "Mode", skip_serializing_if = "Option::is_none", default)]
+    pub mode: Option<u32>,
+}
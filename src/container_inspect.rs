@@ -1,223 +1,214 @@
-use std::{collections::HashMap};
+use std::collections::HashMap;
 
 use serde::{Serialize, Deserialize};
 
-use crate::{container_structs::Mount, container_network::NetworkSettingsNet};
+use crate::{container_structs::{Mount, EmptyMap}, container_network::NetworkSettings, container_health::HealthConfig, container_state::{ContainerState, HealthStatus, HealthLogEntry}, deserialize::null_as_default};
 
-#[derive(Serialize, Deserialize, Debug)]
+/// The host configuration of an inspected container, same as the one used to create it.
+pub type HostConfig = crate::container_host_config::HostConfig;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct InspectedContainer {
-    #[serde(rename = "AppArmorProfile", default)]
-    pub app_armor_profile: String,
-    #[serde(rename = "Args", default)]
-    pub args: Vec<String>,
-    #[serde(rename = "Config", default)]
-    pub config: Config,
+    #[serde(rename = "Id", default)]
+    pub id: String,
     #[serde(rename = "Created", default)]
     pub created: String,
-    #[serde(rename = "Driver", default)]
-    pub driver: String,
-    #[serde(rename = "ExecIDs", default)]
-    pub exec_ids: Vec<String>,
-    #[serde(rename = "HostConfig", default)]
-    pub host_config: HostConfig,
+    #[serde(rename = "Path", default)]
+    pub path: String,
+    #[serde(rename = "Args", deserialize_with = "null_as_default", default)]
+    pub args: Vec<String>,
+    #[serde(rename = "State", default)]
+    pub state: State,
+    #[serde(rename = "Image", default)]
+    pub image: String,
+    #[serde(rename = "ResolvConfPath", default)]
+    pub resolv_conf_path: String,
     #[serde(rename = "HostnamePath", default)]
     pub hostname_path: String,
     #[serde(rename = "HostsPath", default)]
     pub hosts_path: String,
-    #[serde(rename = "Id", default)]
-    pub id: String,
-    #[serde(rename = "Image", default)]
-    pub image: String,
     #[serde(rename = "LogPath", default)]
     pub log_path: String,
-    #[serde(rename = "MountLabel", default)]
-    pub mount_label: String,
-    #[serde(rename = "Mounts", default)]
-    pub mounts: Vec<Mount>,
     #[serde(rename = "Name", default)]
     pub name: String,
-    #[serde(rename = "NetworkSettings", default)]
-    pub network_settings: NetworkSettingsNet,
-    #[serde(rename = "Path", default)]
-    pub path: String,
-    #[serde(rename = "ProcessLabel", default)]
-    pub process_label: String,
-    #[serde(rename = "ResolvConfPath", default)]
-    pub resolv_conf_path: String,
     #[serde(rename = "RestartCount", default)]
     pub restart_count: u64,
-    #[serde(rename = "State", default)]
-    pub state: State,
+    #[serde(rename = "Driver", default)]
+    pub driver: String,
+    #[serde(rename = "Platform", default)]
+    pub platform: String,
+    #[serde(rename = "MountLabel", default)]
+    pub mount_label: String,
+    #[serde(rename = "ProcessLabel", default)]
+    pub process_label: String,
+    #[serde(rename = "AppArmorProfile", default)]
+    pub app_armor_profile: String,
+    #[serde(rename = "ExecIDs", deserialize_with = "null_as_default", default)]
+    pub exec_ids: Vec<String>,
+    #[serde(rename = "HostConfig", default)]
+    pub host_config: HostConfig,
+    #[serde(rename = "GraphDriver", default)]
+    pub graph_driver: GraphDriver,
+    /// The size of files that have been created or changed by this container, only set when inspected with `size`.
+    #[serde(rename = "SizeRw", skip_serializing_if = "Option::is_none", default)]
+    pub size_rw: Option<i64>,
+    /// The total size of all the files in this container, only set when inspected with `size`.
+    #[serde(rename = "SizeRootFs", skip_serializing_if = "Option::is_none", default)]
+    pub size_root_fs: Option<i64>,
+    #[serde(rename = "Mounts", deserialize_with = "null_as_default", default)]
+    pub mounts: Vec<Mount>,
+    #[serde(rename = "Config", default)]
+    pub config: Config,
+    #[serde(rename = "NetworkSettings", default)]
+    pub network_settings: NetworkSettings,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Config {
-    #[serde(rename = "AttachStderr", default)]
-    pub attach_stderr: bool,
+    #[serde(rename = "Hostname", default)]
+    pub hostname: String,
+    #[serde(rename = "Domainname", default)]
+    pub domainname: String,
+    #[serde(rename = "User", default)]
+    pub user: String,
     #[serde(rename = "AttachStdin", default)]
     pub attach_stdin: bool,
     #[serde(rename = "AttachStdout", default)]
     pub attach_stdout: bool,
-    #[serde(rename = "Cmd", default)]
-    pub cmd: Vec<String>,
-    #[serde(rename = "Domainname", default)]
-    pub domainname: String,
-    #[serde(rename = "Entrypoint", default)]
-    pub entrypoint: Vec<String>,
-    #[serde(rename = "Env", default)]
-    pub env: Vec<String>,
-    #[serde(rename = "ExposedPorts", default)]
-    pub exposed_ports: Vec<String>,
-    #[serde(rename = "Hostname", default)]
-    pub hostname: String,
-    #[serde(rename = "Image", default)]
-    pub image: String,
-    #[serde(rename = "Labels", default)]
-    pub labels: Vec<String>,
-    #[serde(rename = "OnBuild", default)]
-    pub on_build: Vec<String>,
+    #[serde(rename = "AttachStderr", default)]
+    pub attach_stderr: bool,
+    #[serde(rename = "ExposedPorts", deserialize_with = "null_as_default", default)]
+    pub exposed_ports: HashMap<String, EmptyMap>,
+    #[serde(rename = "Tty", default)]
+    pub tty: bool,
     #[serde(rename = "OpenStdin", default)]
     pub open_stdin: bool,
     #[serde(rename = "StdinOnce", default)]
     pub stdin_once: bool,
-    #[serde(rename = "Tty", default)]
-    pub tty: bool,
-    #[serde(rename = "User", default)]
-    pub user: String,
-    #[serde(rename = "Volumes", default)]
-    pub volumes: Vec<String>,
+    #[serde(rename = "Env", deserialize_with = "null_as_default", default)]
+    pub env: Vec<String>,
+    #[serde(rename = "Cmd", deserialize_with = "null_as_default", default)]
+    pub cmd: Vec<String>,
+    #[serde(rename = "Healthcheck", skip_serializing_if = "Option::is_none", default)]
+    pub healthcheck: Option<HealthConfig>,
+    #[serde(rename = "ArgsEscaped", default)]
+    pub args_escaped: bool,
+    #[serde(rename = "Image", default)]
+    pub image: String,
+    #[serde(rename = "Volumes", deserialize_with = "null_as_default", default)]
+    pub volumes: HashMap<String, EmptyMap>,
     #[serde(rename = "WorkingDir", default)]
     pub working_dir: String,
+    #[serde(rename = "Entrypoint", deserialize_with = "null_as_default", default)]
+    pub entrypoint: Vec<String>,
+    #[serde(rename = "NetworkDisabled", default)]
+    pub network_disabled: bool,
+    #[serde(rename = "MacAddress", default)]
+    pub mac_address: String,
+    #[serde(rename = "OnBuild", deserialize_with = "null_as_default", default)]
+    pub on_build: Vec<String>,
+    #[serde(rename = "Labels", deserialize_with = "null_as_default", default)]
+    pub labels: HashMap<String, String>,
     #[serde(rename = "StopSignal", default)]
     pub stop_signal: String,
-    #[serde(rename = "StopTimeout", default)]
-    pub stop_timeout: u64,
-    #[serde(rename = "HealthCheck", default)]
-    pub health_check: HashMap<String, Vec<String>>,
+    /// Timeout to stop the container in seconds, only set when it was given at creation.
+    #[serde(rename = "StopTimeout", skip_serializing_if = "Option::is_none", default)]
+    pub stop_timeout: Option<i64>,
+    #[serde(rename = "Shell", deserialize_with = "null_as_default", default)]
+    pub shell: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct State {
-    #[serde(rename = "Error", default)]
-    pub error: String,
-    #[serde(rename = "ExitCode", default)]
-    pub exit_code: u64,
-    #[serde(rename = "FinishedAt", default)]
-    pub finished_at: String,
-    #[serde(rename = "OOMKilled", default)]
-    pub oom_killed: bool,
+    #[serde(rename = "Status", default)]
+    pub status: ContainerState,
+    #[serde(rename = "Running", default)]
+    pub running: bool,
     #[serde(rename = "Paused", default)]
     pub paused: bool,
-    #[serde(rename = "Pid", default)]
-    pub pid: u64,
     #[serde(rename = "Restarting", default)]
     pub restarting: bool,
-    #[serde(rename = "Running", default)]
-    pub running: bool,
+    #[serde(rename = "OOMKilled", default)]
+    pub oom_killed: bool,
+    #[serde(rename = "Dead", default)]
+    pub dead: bool,
+    #[serde(rename = "Pid", default)]
+    pub pid: u64,
+    #[serde(rename = "ExitCode", default)]
+    pub exit_code: i64,
+    #[serde(rename = "Error", default)]
+    pub error: String,
     #[serde(rename = "StartedAt", default)]
     pub started_at: String,
-    #[serde(rename = "Status", default)]
-    pub status: StateHealth,
+    #[serde(rename = "FinishedAt", default)]
+    pub finished_at: String,
+    /// The health of the container, only set when it has a health check.
+    #[serde(rename = "Health", skip_serializing_if = "Option::is_none", default)]
+    pub health: Option<StateHealth>,
+}
+
+#[cfg(feature = "chrono")]
+impl InspectedContainer {
+    /// Returns the creation time of the container, `None` when it is not reported or cannot be parsed.
+    pub fn created_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.created).ok().flatten()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl State {
+    /// Returns when the container last started, `None` when it never started.
+    pub fn started_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.started_at).ok().flatten()
+    }
+
+    /// Returns when the container last stopped, `None` when it never stopped.
+    pub fn finished_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.finished_at).ok().flatten()
+    }
+
+    /// Returns how long the container has been running, or ran before it stopped.
+    pub fn uptime(&self) -> Option<chrono::Duration> {
+        let started_at = self.started_time()?;
+        let until = match self.finished_time() {
+            Some(finished_at) if !self.running && finished_at >= started_at => finished_at,
+            _ => chrono::Utc::now(),
+        };
+
+        Some(until - started_at)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct StateHealth {
+    #[serde(rename = "Status", default)]
+    pub status: HealthStatus,
     #[serde(rename = "FailingStreak", default)]
     pub failing_streak: u64,
-    #[serde(rename = "Log", default)]
-    pub log: Vec<HashMap<String, serde_json::Value>>,
-    #[serde(rename = "Status", default)]
-    pub status: String,
+    /// The results of the last checks, oldest first.
+    #[serde(rename = "Log", deserialize_with = "null_as_default", default)]
+    pub log: Vec<HealthLogEntry>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct HostConfig {
-    #[serde(rename = "MaximumIops", default)] 
-    pub maximum_iops: i64,
-    #[serde(rename = "MaximumIOBps", default)]
-    pub maximum_io_bps: i64,
-    #[serde(rename = "BlkioWeight", default)]
-    pub blkio_weight: i16,
-    #[serde(rename = "BlkioWeightDevice", default)]
-    pub blkio_weight_device: Vec<HashMap<String, String>>,
-    #[serde(rename = "BlkioDeviceReadBps", default)]
-    pub blkio_device_read_bps: Vec<HashMap<String, String>>,
-    #[serde(rename = "BlkioDeviceWriteBps", default)]
-    pub blkio_device_write_bps: Vec<HashMap<String, String>>,
-    #[serde(rename = "BlkioDeviceReadIOps", default)]
-    pub blkio_device_read_iops: Vec<HashMap<String, String>>,
-    #[serde(rename = "BlkioDeviceWriteIOps", default)]
-    pub blkio_device_write_iops: Vec<HashMap<String, String>>,
-    #[serde(rename = "ContainerIDFile", default)]
-    pub container_id_file: String,
-    #[serde(rename = "CpusetCpus", default)]
-    pub cpuset_cpus: String,
-    #[serde(rename = "CpusetMems", default)]
-    pub cpuset_mems: String,
-    #[serde(rename = "CpuPercent", default)]
-    pub cpu_percent: u64,
-    #[serde(rename = "CpuShares", default)]
-    pub cpu_shares: u64,
-    #[serde(rename = "CpuPeriod", default)]
-    pub cpu_period: i64,
-    #[serde(rename = "CpuRealtimePeriod", default)]
-    pub cpu_realtime_period: i64,
-    #[serde(rename = "CpuRealtimeRuntime", default)]
-    pub cpu_realtime_runtime: i64,
-    #[serde(rename = "Devices", default)]
-    pub devices: Vec<HashMap<String, serde_json::Value>>,
-    #[serde(rename = "DeviceRequests", default)]
-    pub device_requests: Vec<DeviceRequest>,
-    #[serde(rename = "IpcMode", default)]
-    pub ipc_mode: String,
-    #[serde(rename = "Memory", default)]
-    pub memory: i64,
-    #[serde(rename = "MemorySwap", default)]
-    pub memory_swap: i64,
-    #[serde(rename = "MemoryReservation", default)]
-    pub memory_reservation: i64,
-    #[serde(rename = "KernelMemory", default)]
-    pub kernel_memory: i64,
-    #[serde(rename = "OomKillDisable", default)]
-    pub oom_kill_disable: bool,
-    #[serde(rename = "OomScoreAdj", default)]
-    pub oom_score_adj: i16,
-    #[serde(rename = "NetworkMode", default)]
-    pub network_mode: String,
-    #[serde(rename = "PidMode", default)]
-    pub pid_mode: String,
-    #[serde(rename = "PortBindings", default)]
-    pub port_bindings: HashMap<String, Vec<HashMap<String, String>>>,
-    #[serde(rename = "Privileged", default)]
-    pub privileged: bool,
-    #[serde(rename = "ReadonlyRootfs", default)]
-    pub readonly_rootfs: bool,
-    #[serde(rename = "PublishAllPorts", default)]
-    pub publish_all_ports: bool,
-    #[serde(rename = "RestartPolicy", default)]
-    pub restart_policy: HashMap<String, String>,
-    #[serde(rename = "LogConfig", default)]
-    pub log_config: HashMap<String, String>,
-    #[serde(rename = "Sysctls", default)]
-    pub sysctls: HashMap<String, String>,
-    #[serde(rename = "Ulimits", default)]
-    pub ulimits: Vec<HashMap<String, String>>,
-    #[serde(rename = "VolumeDriver", default)]
-    pub volume_driver: String,
-    #[serde(rename = "ShmSize", default)]
-    pub shm_size: i64,
+/// GraphDriver is the storage driver of the container filesystem and its driver specific data
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GraphDriver {
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "Data", deserialize_with = "null_as_default", default)]
+    pub data: HashMap<String, String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct DeviceRequest {
     #[serde(rename = "Driver", default)]
     pub driver: String,
     #[serde(rename = "Count", default)]
-    pub count: u64,
-    #[serde(rename = "DeviceIDs", default)]
+    pub count: i64,
+    #[serde(rename = "DeviceIDs", deserialize_with = "null_as_default", default)]
     pub device_ids: Vec<String>,
-    #[serde(rename = "Capabilities", default)]
+    #[serde(rename = "Capabilities", deserialize_with = "null_as_default", default)]
     pub capabilities: Vec<Vec<String>>,
-    #[serde(rename = "Options", default)]
+    #[serde(rename = "Options", deserialize_with = "null_as_default", default)]
     pub options: HashMap<String, String>,
-}
\ No newline at end of file
+}
@@ -0,0 +1,284 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    container_host_config::{BindOptions, MountSpec, TmpfsOptions, VolumeDriverConfig, VolumeOptions},
+    container_structs::Mount,
+    units::parse_bytes,
+    error::invalid,
+};
+
+const PROPAGATIONS: [&str; 6] = ["private", "rprivate", "shared", "rshared", "slave", "rslave"];
+const CONSISTENCIES: [&str; 4] = ["default", "consistent", "cached", "delegated"];
+
+/// Relabel is the SELinux label applied to the source of a bind mount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relabel {
+    /// `z`, the content is shared between containers.
+    Shared,
+    /// `Z`, the content is private to the container.
+    Private,
+}
+
+/// VolumeSpec is a mount in `docker run -v` notation, `[source:]target[:options]`
+///
+/// The source is an absolute host path for a bind mount, or a volume name; without a source an anonymous volume is created.
+/// The options are comma separated: `ro` or `rw`, `z` or `Z`, `nocopy`, a propagation mode and a consistency mode.
+///
+/// ```rust,ignore
+/// use docker_engine_api::container_mounts::VolumeSpec;
+///
+/// let bind: VolumeSpec = "/srv/www:/usr/share/nginx/html:ro,z".parse()?;
+/// let volume: VolumeSpec = "pgdata:/var/lib/postgresql/data".parse()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct VolumeSpec {
+    pub mount: MountSpec,
+    /// The Engine only relabels the sources given in `Binds`, it has no equivalent in `Mounts`.
+    pub relabel: Option<Relabel>,
+}
+
+impl std::str::FromStr for VolumeSpec {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = value.split(':').collect();
+        let (source, target, options) = match parts.as_slice() {
+            [target] => ("", *target, ""),
+            [source, target] => (*source, *target, ""),
+            [source, target, options] => (*source, *target, *options),
+            _ => return Err(invalid(format!("invalid volume specification: '{}'", value))),
+        };
+
+        let mut mount = MountSpec { target: target.to_string(), ..Default::default() };
+        if source.is_empty() {
+            if parts.len() > 1 {
+                return Err(invalid(format!("invalid volume specification: '{}'", value)));
+            }
+            mount._type = "volume".to_string();
+        } else if source.starts_with('/') {
+            mount._type = "bind".to_string();
+            mount.source = source.to_string();
+        } else if is_volume_name(source) {
+            mount._type = "volume".to_string();
+            mount.source = source.to_string();
+        } else {
+            return Err(invalid(format!("the host path must be absolute, relative host paths are not allowed: '{}'", source)));
+        }
+
+        let mut relabel = None;
+        let mut access = None;
+        for option in options.split(',').filter(|option| !option.is_empty()) {
+            let duplicated = match option {
+                "ro" | "rw" => access.replace(option).is_some(),
+                "z" => relabel.replace(Relabel::Shared).is_some(),
+                "Z" => relabel.replace(Relabel::Private).is_some(),
+                "nocopy" if mount._type == "volume" => mount.volume_options.get_or_insert_with(VolumeOptions::default).no_copy.replace(true).is_some(),
+                option if PROPAGATIONS.contains(&option) && mount._type == "bind" => {
+                    mount.bind_options.get_or_insert_with(BindOptions::default).propagation.replace(option.to_string()).is_some()
+                },
+                option if CONSISTENCIES.contains(&option) => mount.consistency.replace(option.to_string()).is_some(),
+                _ => return Err(invalid(format!("invalid option '{}' for a {} in volume specification: '{}'", option, mount._type, value))),
+            };
+
+            if duplicated {
+                return Err(invalid(format!("conflicting options in volume specification: '{}'", value)));
+            }
+        }
+        mount.read_only = access.map(|access| access == "ro");
+
+        if relabel.is_some() && mount.source.is_empty() {
+            return Err(invalid(format!("an anonymous volume cannot be relabeled: '{}'", value)));
+        }
+
+        validate_target(&mount.target)?;
+        Ok(VolumeSpec { mount, relabel })
+    }
+}
+
+impl VolumeSpec {
+    /// Returns the entry of `Binds` for the mount, `None` for an anonymous volume which is only expressed in `Mounts`.
+    pub fn bind(&self) -> Option<String> {
+        if self.mount.source.is_empty() {
+            return None;
+        }
+
+        let mut options = vec![];
+        match self.mount.read_only {
+            Some(true) => options.push("ro"),
+            Some(false) => options.push("rw"),
+            None => {},
+        }
+        match self.relabel {
+            Some(Relabel::Shared) => options.push("z"),
+            Some(Relabel::Private) => options.push("Z"),
+            None => {},
+        }
+        if self.mount.volume_options.as_ref().and_then(|volume_options| volume_options.no_copy) == Some(true) {
+            options.push("nocopy");
+        }
+        if let Some(propagation) = self.mount.bind_options.as_ref().and_then(|bind_options| bind_options.propagation.as_deref()) {
+            options.push(propagation);
+        }
+        if let Some(consistency) = self.mount.consistency.as_deref() {
+            options.push(consistency);
+        }
+
+        if options.is_empty() {
+            return Some(format!("{}:{}", self.mount.source, self.mount.target));
+        }
+        Some(format!("{}:{}:{}", self.mount.source, self.mount.target, options.join(",")))
+    }
+}
+
+impl fmt::Display for VolumeSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bind() {
+            Some(bind) => write!(f, "{}", bind),
+            None => write!(f, "{}", self.mount.target),
+        }
+    }
+}
+
+/// Parses a mount in `docker run --mount` notation, comma separated `key=value` options.
+///
+/// The keys are `type` (`bind`, `volume` or `tmpfs`, `volume` by default), `source` (`src`), `target` (`destination`, `dst`),
+/// `readonly` (`ro`), `consistency`, `bind-propagation`, `bind-nonrecursive`, `volume-nocopy`, `volume-driver`,
+/// `volume-label`, `volume-opt`, `tmpfs-size` and `tmpfs-mode`.
+/// Boolean options can be given without a value to set them.
+pub fn parse_mount(value: &str) -> Result<MountSpec, Box<dyn std::error::Error + Send + Sync>> {
+    let error = |message: &str| invalid(format!("{} in mount specification: '{}'", message, value));
+    let mut mount = MountSpec { _type: "volume".to_string(), ..Default::default() };
+    let mut bind_options = BindOptions::default();
+    let mut volume_options = VolumeOptions::default();
+    let mut volume_driver = VolumeDriverConfig::default();
+    let mut tmpfs_options = TmpfsOptions::default();
+    let mut options_type = None;
+
+    for option in value.split(',').map(str::trim).filter(|option| !option.is_empty()) {
+        let (key, option_value) = match option.split_once('=') {
+            Some((key, option_value)) => (key.to_lowercase(), Some(option_value)),
+            None => (option.to_lowercase(), None),
+        };
+        let flag = || match option_value.map(|flag| flag.to_lowercase()).as_deref() {
+            None | Some("true") | Some("1") => Ok(true),
+            Some("false") | Some("0") => Ok(false),
+            Some(_) => Err(error(&format!("invalid value for '{}'", key))),
+        };
+        let text = || option_value.filter(|text| !text.is_empty()).ok_or_else(|| error(&format!("missing value for '{}'", key)));
+        let key_value = || text().map(|text| text.split_once('=').unwrap_or((text, "")));
+
+        match key.as_str() {
+            "type" => mount._type = text()?.to_string(),
+            "source" | "src" => mount.source = text()?.to_string(),
+            "target" | "destination" | "dst" => mount.target = text()?.to_string(),
+            "readonly" | "ro" => mount.read_only = Some(flag()?),
+            "consistency" => match text()? {
+                consistency if CONSISTENCIES.contains(&consistency) => mount.consistency = Some(consistency.to_string()),
+                _ => return Err(error("invalid consistency")),
+            },
+            "bind-propagation" => match text()? {
+                propagation if PROPAGATIONS.contains(&propagation) => bind_options.propagation = Some(propagation.to_string()),
+                _ => return Err(error("invalid bind propagation")),
+            },
+            "bind-nonrecursive" => bind_options.non_recursive = Some(flag()?),
+            "volume-nocopy" => volume_options.no_copy = Some(flag()?),
+            "volume-driver" => volume_driver.name = text()?.to_string(),
+            "volume-label" => {
+                let (label, label_value) = key_value()?;
+                volume_options.labels.get_or_insert_with(HashMap::new).insert(label.to_string(), label_value.to_string());
+            },
+            "volume-opt" => {
+                let (opt, opt_value) = key_value()?;
+                volume_driver.options.insert(opt.to_string(), opt_value.to_string());
+            },
+            "tmpfs-size" => tmpfs_options.size_bytes = Some(parse_bytes(text()?)?),
+            "tmpfs-mode" => match u32::from_str_radix(text()?, 8) {
+                Ok(mode) => tmpfs_options.mode = Some(mode),
+                Err(_) => return Err(error("invalid tmpfs mode, it should be octal")),
+            },
+            _ => return Err(error(&format!("unknown option '{}'", key))),
+        }
+
+        // Options of a type can only be given for a mount of that type
+        if let Some((prefix, _)) = key.split_once('-') {
+            if options_type.get_or_insert(prefix.to_string()) != prefix {
+                return Err(error("conflicting options"));
+            }
+        }
+    }
+
+    match (mount._type.as_str(), options_type.as_deref()) {
+        ("bind" | "volume" | "tmpfs", None) => {},
+        (mount_type, Some(options_type)) if mount_type == options_type => {},
+        ("bind" | "volume" | "tmpfs", Some(options_type)) => return Err(error(&format!("{} options cannot be used with a {} mount", options_type, mount._type))),
+        _ => return Err(error(&format!("invalid type '{}'", mount._type))),
+    }
+
+    match mount._type.as_str() {
+        "bind" if !mount.source.starts_with('/') => return Err(error("the source of a bind mount must be an absolute host path")),
+        "volume" if !mount.source.is_empty() && !is_volume_name(&mount.source) => return Err(error("invalid volume name")),
+        "tmpfs" if !mount.source.is_empty() => return Err(error("a tmpfs mount cannot have a source")),
+        _ => {},
+    }
+
+    if options_type.as_deref() == Some("bind") {
+        mount.bind_options = Some(bind_options);
+    }
+    if options_type.as_deref() == Some("volume") {
+        if !volume_driver.name.is_empty() || !volume_driver.options.is_empty() {
+            volume_options.driver_config = Some(volume_driver);
+        }
+        mount.volume_options = Some(volume_options);
+    }
+    if options_type.as_deref() == Some("tmpfs") {
+        mount.tmpfs_options = Some(tmpfs_options);
+    }
+
+    validate_target(&mount.target)?;
+    Ok(mount)
+}
+
+impl From<&Mount> for MountSpec {
+    /// Builds the mount to add to a new container from a mount of an inspected or listed container, e.g. to recreate it.
+    fn from(mount: &Mount) -> Self {
+        let mut spec = MountSpec {
+            target: mount.destination.clone(),
+            source: if mount._type == "volume" { mount.name.clone() } else { mount.source.clone() },
+            _type: mount._type.clone(),
+            read_only: Some(!mount.rw),
+            ..Default::default()
+        };
+
+        if mount._type == "bind" && !mount.propagation.is_empty() {
+            spec.bind_options = Some(BindOptions { propagation: Some(mount.propagation.clone()), ..Default::default() });
+        }
+        if mount._type == "volume" && !mount.driver.is_empty() && mount.driver != "local" {
+            let driver_config = VolumeDriverConfig { name: mount.driver.clone(), ..Default::default() };
+            spec.volume_options = Some(VolumeOptions { driver_config: Some(driver_config), ..Default::default() });
+        }
+
+        spec
+    }
+}
+
+/// A volume name starts with a letter or a digit, followed by at least one letter, digit, `_`, `.` or `-`.
+fn is_volume_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.len() > 1
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+}
+
+fn validate_target(target: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if target.is_empty() {
+        return Err(invalid("the mount target is missing".to_string()));
+    }
+    if !target.starts_with('/') {
+        return Err(invalid(format!("the mount target must be an absolute path: '{}'", target)));
+    }
+    if target == "/" {
+        return Err(invalid("the mount target cannot be '/'".to_string()));
+    }
+
+    Ok(())
+}
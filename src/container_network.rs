@@ -1,86 +1,125 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
+use crate::{deserialize::null_as_default, container_host_config::PortBinding};
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct HostConfig {
     #[serde(rename = "NetworkMode", default)]
     pub network_mode: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Default, Clone)]
-pub struct NetworkSettingsNet {
-    #[serde(rename = "NetworkID", default)]
+/// EndpointSettings is the configuration of a container for a network, used both to connect a container at creation
+/// and to report its connection when inspecting or listing it
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct EndpointSettings {
+    #[serde(rename = "IPAMConfig", skip_serializing_if = "Option::is_none", default)]
+    pub ipam_config: Option<IPAMConfig>,
+    #[serde(rename = "Links", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
+    pub links: Vec<String>,
+    #[serde(rename = "Aliases", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
+    pub aliases: Vec<String>,
+    #[serde(rename = "NetworkID", skip_serializing_if = "String::is_empty", default)]
     pub network_id: String,
-    #[serde(rename = "EndpointID", default)]
+    #[serde(rename = "EndpointID", skip_serializing_if = "String::is_empty", default)]
     pub endpoint_id: String,
-    #[serde(rename = "Gateway", default)]
+    #[serde(rename = "Gateway", skip_serializing_if = "String::is_empty", default)]
     pub gateway: String,
-    #[serde(rename = "IPAddress", default)]
+    #[serde(rename = "IPAddress", skip_serializing_if = "String::is_empty", default)]
     pub ip_address: String,
-    #[serde(rename = "IPPrefixLen", default)]
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "is_zero", default)]
     pub ip_prefix_len: u8,
-    #[serde(rename = "IPv6Gateway", default)]
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "String::is_empty", default)]
     pub ipv6_gateway: String,
-    #[serde(rename = "GlobalIPv6Address", default)]
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "String::is_empty", default)]
     pub global_ipv6_address: String,
-    #[serde(rename = "GlobalIPv6PrefixLen", default)]
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "is_zero", default)]
     pub global_ipv6_prefix_len: u8,
-    #[serde(rename = "MacAddress", default)]
+    #[serde(rename = "MacAddress", skip_serializing_if = "String::is_empty", default)]
     pub mac_address: String,
+    /// The names the container can be resolved by on the network (API 1.44+).
+    #[serde(rename = "DNSNames", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
+    pub dns_names: Vec<String>,
+    /// Driver specific options for the endpoint.
+    #[serde(rename = "DriverOpts", skip_serializing_if = "HashMap::is_empty", deserialize_with = "null_as_default", default)]
+    pub driver_opts: HashMap<String, String>,
 }
 
+/// NetworkSettingsNet is kept for compatibility, it is the same as `EndpointSettings`
+pub type NetworkSettingsNet = EndpointSettings;
+
+/// NetworkSettings is the network configuration of a container
+///
+/// Listing containers only reports `Networks`, the other fields are reported when inspecting a container.
+/// The endpoint fields at the top level (`EndpointID`, `IPAddress`, ...) belong to the default bridge network and are deprecated by the Engine.
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct NetworkSettings {
-    #[serde(rename = "Networks", default)]
-    pub networks: HashMap<String, NetworkSettingsNet>,
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct NetworkingConfig {
-    #[serde(rename = "EndpointsConfig")]
-    pub endpoints_config: EndpointsConfig,
+    #[serde(rename = "Bridge", skip_serializing_if = "String::is_empty", default)]
+    pub bridge: String,
+    #[serde(rename = "SandboxID", skip_serializing_if = "String::is_empty", default)]
+    pub sandbox_id: String,
+    #[serde(rename = "SandboxKey", skip_serializing_if = "String::is_empty", default)]
+    pub sandbox_key: String,
+    #[serde(rename = "HairpinMode", default)]
+    pub hairpin_mode: bool,
+    #[serde(rename = "LinkLocalIPv6Address", skip_serializing_if = "String::is_empty", default)]
+    pub link_local_ipv6_address: String,
+    #[serde(rename = "LinkLocalIPv6PrefixLen", skip_serializing_if = "is_zero", default)]
+    pub link_local_ipv6_prefix_len: u8,
+    /// The host bindings of each exposed port (`80/tcp`), `None` when the port is exposed but not published.
+    #[serde(rename = "Ports", deserialize_with = "null_as_default", default)]
+    pub ports: HashMap<String, Option<Vec<PortBinding>>>,
+    #[serde(rename = "SecondaryIPAddresses", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
+    pub secondary_ip_addresses: Vec<Address>,
+    #[serde(rename = "SecondaryIPv6Addresses", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
+    pub secondary_ipv6_addresses: Vec<Address>,
+    #[serde(rename = "EndpointID", skip_serializing_if = "String::is_empty", default)]
+    pub endpoint_id: String,
+    #[serde(rename = "Gateway", skip_serializing_if = "String::is_empty", default)]
+    pub gateway: String,
+    #[serde(rename = "GlobalIPv6Address", skip_serializing_if = "String::is_empty", default)]
+    pub global_ipv6_address: String,
+    #[serde(rename = "GlobalIPv6PrefixLen", skip_serializing_if = "is_zero", default)]
+    pub global_ipv6_prefix_len: u8,
+    #[serde(rename = "IPAddress", skip_serializing_if = "String::is_empty", default)]
+    pub ip_address: String,
+    #[serde(rename = "IPPrefixLen", skip_serializing_if = "is_zero", default)]
+    pub ip_prefix_len: u8,
+    #[serde(rename = "IPv6Gateway", skip_serializing_if = "String::is_empty", default)]
+    pub ipv6_gateway: String,
+    #[serde(rename = "MacAddress", skip_serializing_if = "String::is_empty", default)]
+    pub mac_address: String,
+    #[serde(rename = "Networks", deserialize_with = "null_as_default", default)]
+    pub networks: HashMap<String, EndpointSettings>,
 }
 
-impl Default for NetworkingConfig {
-    fn default() -> Self {
-        NetworkingConfig {
-            endpoints_config: EndpointsConfig {
-                isolated_nw: IsolatedNw {
-                    ipam_config: IPAMConfig {
-                        link_local_ips: Vec::new(),
-                        ipv4_address: String::new(),
-                        ipv6_address: String::new(),
-                    },
-                    links: Vec::new(),
-                    aliases: Vec::new(),
-                },
-            },
-        }
-    }
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Address {
+    #[serde(rename = "Addr", default)]
+    pub addr: String,
+    #[serde(rename = "PrefixLen", default)]
+    pub prefix_len: u8,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct EndpointsConfig {
-    #[serde(rename = "isolated_nw")]
-    pub isolated_nw: IsolatedNw,
-}
+/// EndpointsConfig maps the name (or id) of each network the container is connected to its settings
+pub type EndpointsConfig = HashMap<String, EndpointSettings>;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct IsolatedNw {
-    #[serde(rename = "IPAMConfig")]
-    pub ipam_config: IPAMConfig,
-    #[serde(rename = "Links", default)]
-    pub links: Vec<String>,
-    #[serde(rename = "Aliases", default)]
-    pub aliases: Vec<String>,
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct NetworkingConfig {
+    #[serde(rename = "EndpointsConfig", default)]
+    pub endpoints_config: EndpointsConfig,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct IPAMConfig {
-    #[serde(rename = "IPv4Address", default)]
+    #[serde(rename = "IPv4Address", skip_serializing_if = "String::is_empty", default)]
     pub ipv4_address: String,
-    #[serde(rename = "IPv6Address", default)]
+    #[serde(rename = "IPv6Address", skip_serializing_if = "String::is_empty", default)]
     pub ipv6_address: String,
-    #[serde(rename = "LinkLocalIPs", default)]
+    #[serde(rename = "LinkLocalIPs", skip_serializing_if = "Vec::is_empty", deserialize_with = "null_as_default", default)]
     pub link_local_ips: Vec<String>,
-}
\ No newline at end of file
+}
+
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
@@ -0,0 +1,243 @@
+use serde::{Serialize, Deserialize};
+use std::{collections::HashMap, fmt, net::{IpAddr, Ipv4Addr, SocketAddr}, str::FromStr};
+
+use crate::{
+    container_host_config::{HostConfig, PortBinding},
+    container_network::NetworkSettings,
+    container_structs::Container,
+    error::invalid,
+};
+
+/// Protocol is the transport protocol of a container port
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Protocol {
+    #[default]
+    #[serde(rename = "tcp")]
+    Tcp,
+    #[serde(rename = "udp")]
+    Udp,
+    #[serde(rename = "sctp")]
+    Sctp,
+}
+
+impl Protocol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+            Protocol::Sctp => "sctp",
+        }
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "tcp" => Ok(Protocol::Tcp),
+            "udp" => Ok(Protocol::Udp),
+            "sctp" => Ok(Protocol::Sctp),
+            _ => Err(invalid(format!("invalid protocol: '{}'", value))),
+        }
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Formats a container port as the key used by `ExposedPorts` and `PortBindings`, e.g. `80/tcp`.
+pub fn port_key(port: u16, protocol: Protocol) -> String {
+    format!("{}/{}", port, protocol)
+}
+
+/// PortSpec is a port publication in `docker run -p` notation, `[ip:][host port[-end]:]container port[-end][/protocol]`
+///
+/// ```rust,ignore
+/// use docker_engine_api::container_ports::PortSpec;
+///
+/// let web: PortSpec = "8080:80".parse()?;
+/// let dns: PortSpec = "127.0.0.1::53/udp".parse()?;
+/// let range: PortSpec = "3000-3005:3000-3005".parse()?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortSpec {
+    /// Host IP address the ports are published on, all interfaces when `None`.
+    pub host_ip: Option<IpAddr>,
+    /// First and last host ports, a random host port when `None`.
+    pub host_ports: Option<(u16, u16)>,
+    /// First and last container ports.
+    pub container_ports: (u16, u16),
+    pub protocol: Protocol,
+}
+
+impl PortSpec {
+    /// Returns the keys of the exposed container ports, e.g. `80/tcp`.
+    pub fn exposed_ports(&self) -> Vec<String> {
+        let (first, last) = self.container_ports;
+        (first..=last).map(|port| port_key(port, self.protocol)).collect()
+    }
+
+    /// Returns the binding of each container port.
+    ///
+    /// A single container port published on a range of host ports is bound to the whole range, the Engine picks a free port in it.
+    pub fn port_bindings(&self) -> Vec<(String, PortBinding)> {
+        let host_ip = self.host_ip.map(|ip| ip.to_string()).unwrap_or_default();
+        let (first, last) = self.container_ports;
+        (first..=last).map(|port| {
+            let host_port = match self.host_ports {
+                None => String::new(),
+                Some((host_first, host_last)) if first == last && host_first != host_last => format!("{}-{}", host_first, host_last),
+                Some((host_first, _)) => (host_first + (port - first)).to_string(),
+            };
+
+            (port_key(port, self.protocol), PortBinding { host_ip: host_ip.clone(), host_port })
+        }).collect()
+    }
+}
+
+impl FromStr for PortSpec {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || invalid(format!("invalid port specification: '{}'", value));
+        let (addresses, protocol) = match value.rsplit_once('/') {
+            Some((addresses, protocol)) => (addresses, protocol.parse()?),
+            None => (value, Protocol::Tcp),
+        };
+
+        // An IPv6 host address is written in brackets, the other parts are separated by colons
+        let (host_ip, ports) = match addresses.strip_prefix('[') {
+            Some(bracketed) => match bracketed.split_once("]:") {
+                Some((host_ip, ports)) => (Some(host_ip), ports),
+                None => return Err(error()),
+            },
+            None => (None, addresses),
+        };
+        let parts: Vec<&str> = ports.split(':').collect();
+        let (host_ip, host_ports, container_ports) = match (host_ip, parts.as_slice()) {
+            (None, [container_ports]) => (None, "", *container_ports),
+            (None, [host_ports, container_ports]) => (None, *host_ports, *container_ports),
+            (None, [host_ip, host_ports, container_ports]) => (Some(*host_ip), *host_ports, *container_ports),
+            (Some(host_ip), [host_ports, container_ports]) => (Some(host_ip), *host_ports, *container_ports),
+            _ => return Err(error()),
+        };
+
+        let host_ip = match host_ip.filter(|host_ip| !host_ip.is_empty()) {
+            Some(host_ip) => Some(host_ip.parse::<IpAddr>().map_err(|_| invalid(format!("invalid host IP in port specification: '{}'", value)))?),
+            None => None,
+        };
+        let container_ports = port_range(container_ports).ok_or_else(error)?;
+        let host_ports = match host_ports {
+            "" => None,
+            host_ports => Some(port_range(host_ports).ok_or_else(error)?),
+        };
+
+        if let Some((host_first, host_last)) = host_ports {
+            let (first, last) = container_ports;
+            if first != last && host_last - host_first != last - first {
+                return Err(invalid(format!("the host and container port ranges have different sizes: '{}'", value)));
+            }
+        }
+
+        Ok(PortSpec { host_ip, host_ports, container_ports, protocol })
+    }
+}
+
+impl fmt::Display for PortSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = |(first, last): (u16, u16)| if first == last { first.to_string() } else { format!("{}-{}", first, last) };
+        match self.host_ip {
+            Some(IpAddr::V6(ip)) => write!(f, "[{}]:", ip)?,
+            Some(ip) => write!(f, "{}:", ip)?,
+            None => {},
+        }
+        match self.host_ports {
+            Some(host_ports) => write!(f, "{}:", range(host_ports))?,
+            None if self.host_ip.is_some() => write!(f, ":")?,
+            None => {},
+        }
+        write!(f, "{}/{}", range(self.container_ports), self.protocol)
+    }
+}
+
+/// Parses a port or a range of ports, `80` or `3000-3005`, ports start at 1.
+fn port_range(value: &str) -> Option<(u16, u16)> {
+    let (first, last) = value.split_once('-').unwrap_or((value, value));
+    match (first.parse::<u16>(), last.parse::<u16>()) {
+        (Ok(first), Ok(last)) if first > 0 && first <= last => Some((first, last)),
+        _ => None,
+    }
+}
+
+/// ExposedPorts is the set of ports a new container exposes, keyed as `80/tcp`
+pub type ExposedPorts = HashMap<String, ()>;
+
+/// PortBindings maps each exposed container port (`80/tcp`) to the host ports it is published on
+pub type PortBindings = HashMap<String, Vec<PortBinding>>;
+
+/// Parses port specifications in `docker run -p` notation into the `ExposedPorts` of a new container and the `PortBindings` of its host config.
+pub fn parse_port_specs(specs: &[&str]) -> Result<(ExposedPorts, PortBindings), Box<dyn std::error::Error + Send + Sync>> {
+    let mut exposed_ports = ExposedPorts::new();
+    let mut port_bindings = PortBindings::new();
+    for spec in specs {
+        let spec: PortSpec = spec.parse()?;
+        for key in spec.exposed_ports() {
+            exposed_ports.insert(key, ());
+        }
+        for (key, binding) in spec.port_bindings() {
+            port_bindings.entry(key).or_default().push(binding);
+        }
+    }
+
+    Ok((exposed_ports, port_bindings))
+}
+
+/// Returns the host addresses of port bindings, skipping the bindings without a single host port.
+///
+/// A port published on every interface has an unspecified address (`0.0.0.0` or `::`), it is reached through the loopback interface.
+pub fn host_addresses(bindings: &[PortBinding]) -> Vec<SocketAddr> {
+    bindings.iter().filter_map(|binding| {
+        let port = binding.host_port.parse::<u16>().ok().filter(|port| *port != 0)?;
+        let ip = match binding.host_ip.as_str() {
+            "" => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            host_ip => host_ip.parse().ok()?,
+        };
+
+        Some(SocketAddr::new(ip, port))
+    }).collect()
+}
+
+impl NetworkSettings {
+    /// Returns the host addresses a container port is published on, the random host ports included.
+    pub fn host_addresses(&self, port: u16, protocol: Protocol) -> Vec<SocketAddr> {
+        match self.ports.get(&port_key(port, protocol)) {
+            Some(Some(bindings)) => host_addresses(bindings),
+            _ => vec![],
+        }
+    }
+}
+
+impl HostConfig {
+    /// Returns the host addresses requested for a container port, without the ports left for the Engine to pick.
+    pub fn host_addresses(&self, port: u16, protocol: Protocol) -> Vec<SocketAddr> {
+        match self.port_bindings.as_ref().and_then(|port_bindings| port_bindings.get(&port_key(port, protocol))) {
+            Some(bindings) => host_addresses(bindings),
+            None => vec![],
+        }
+    }
+}
+
+impl Container {
+    /// Returns the host addresses a container port is published on, as reported when listing containers.
+    pub fn host_addresses(&self, port: u16, protocol: Protocol) -> Vec<SocketAddr> {
+        let bindings: Vec<PortBinding> = self.ports.iter()
+            .filter(|p| p.private_port == port && p._type == protocol.as_str())
+            .map(|p| PortBinding { host_ip: p.ip.clone(), host_port: p.public_port.to_string() })
+            .collect();
+        host_addresses(&bindings)
+    }
+}
@@ -1,4 +1,6 @@
 use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet};
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct ContainerProcessesResponse {
     #[serde(rename(deserialize = "Titles"), default)]
@@ -6,3 +8,109 @@ pub struct ContainerProcessesResponse {
     #[serde(rename(deserialize = "Processes"), default)]
     pub processes: Vec<Vec<String>>,
 }
+
+impl ContainerProcessesResponse {
+    /// Returns each row of the response as a `Process`, keyed by the titles of the columns.
+    pub fn records(&self) -> Vec<Process> {
+        self.processes.iter().map(|row| Process::new(&self.titles, row)).collect()
+    }
+
+    /// Rebuilds the process tree from the `PID` and `PPID` columns.
+    ///
+    /// The roots are the processes whose parent is not listed, usually the main process of the container.
+    /// When the `PPID` column is missing (it is not reported by default by `ps aux` and busybox `ps`), every process is a root.
+    pub fn tree(&self) -> Vec<ProcessNode> {
+        let records = self.records();
+        let pids: HashSet<u32> = records.iter().filter_map(|process| process.pid).collect();
+
+        let mut children: HashMap<u32, Vec<usize>> = HashMap::new();
+        let mut roots = vec![];
+        for (index, process) in records.iter().enumerate() {
+            match (process.pid, process.ppid) {
+                (Some(pid), Some(ppid)) if ppid != pid && pids.contains(&ppid) => children.entry(ppid).or_default().push(index),
+                _ => roots.push(index),
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut tree: Vec<ProcessNode> = roots.into_iter().map(|index| ProcessNode::build(index, &records, &children, &mut visited)).collect();
+
+        // Processes whose parents form a cycle are not reachable from a root, keep them as roots instead of dropping them
+        for index in 0..records.len() {
+            if !visited.contains(&index) {
+                tree.push(ProcessNode::build(index, &records, &children, &mut visited));
+            }
+        }
+
+        tree
+    }
+}
+
+/// Process is a row of the processes of a container, with the common columns parsed
+///
+/// The parsed columns are `None` when they are missing from the ps arguments or cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Process {
+    /// The `PID` column.
+    pub pid: Option<u32>,
+    /// The `PPID` column.
+    pub ppid: Option<u32>,
+    /// The `%CPU` column, or `C` as reported by `ps -ef`.
+    pub cpu: Option<f64>,
+    /// The `%MEM` column.
+    pub mem: Option<f64>,
+    /// The `CMD`, `COMMAND` or `ARGS` column.
+    pub command: Option<String>,
+    /// Every column of the row, keyed by its title.
+    pub columns: HashMap<String, String>,
+}
+
+impl Process {
+    pub fn new(titles: &[String], row: &[String]) -> Self {
+        let columns: HashMap<String, String> = titles.iter().cloned().zip(row.iter().cloned()).collect();
+        let column = |names: &[&str]| names.iter().find_map(|name| columns.get(*name)).map(|value| value.trim().to_string());
+
+        Process {
+            pid: column(&["PID"]).and_then(|value| value.parse().ok()),
+            ppid: column(&["PPID"]).and_then(|value| value.parse().ok()),
+            cpu: column(&["%CPU", "C"]).and_then(|value| value.parse().ok()),
+            mem: column(&["%MEM"]).and_then(|value| value.parse().ok()),
+            command: column(&["CMD", "COMMAND", "ARGS"]),
+            columns,
+        }
+    }
+}
+
+/// ProcessNode is a process with the processes it started
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProcessNode {
+    pub process: Process,
+    pub children: Vec<ProcessNode>,
+}
+
+impl ProcessNode {
+    fn build(index: usize, records: &[Process], children: &HashMap<u32, Vec<usize>>, visited: &mut HashSet<usize>) -> Self {
+        visited.insert(index);
+        let process = records[index].clone();
+        let mut nodes = vec![];
+        if let Some(indexes) = process.pid.and_then(|pid| children.get(&pid)) {
+            for child in indexes {
+                if !visited.contains(child) {
+                    nodes.push(ProcessNode::build(*child, records, children, visited));
+                }
+            }
+        }
+
+        ProcessNode { process, children: nodes }
+    }
+
+    /// Returns the process and all its descendants, depth first.
+    pub fn flatten(&self) -> Vec<&Process> {
+        let mut processes = vec![&self.process];
+        for child in &self.children {
+            processes.extend(child.flatten());
+        }
+
+        processes
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+use crate::deserialize::null_as_default;
+
+/// PruneFilters selects which stopped containers are removed by `prune_containers`
+#[derive(Debug, Clone, Default)]
+pub struct PruneFilters {
+    /// Only remove containers created before this timestamp, either a Unix timestamp, a date or a Go duration relative to now (e.g. `24h`).
+    pub until: Option<String>,
+    /// Only remove containers with these labels, as `key` or `key=value`.
+    pub labels: Vec<String>,
+    /// Only remove containers without these labels, as `key` or `key=value`.
+    pub without_labels: Vec<String>,
+}
+
+impl PruneFilters {
+    /// Encodes the filters as the JSON map expected by the `filters` query parameter.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let mut filters: HashMap<&str, Vec<String>> = HashMap::new();
+        if let Some(until) = &self.until {
+            filters.insert("until", vec![until.clone()]);
+        }
+
+        if !self.labels.is_empty() {
+            filters.insert("label", self.labels.clone());
+        }
+
+        if !self.without_labels.is_empty() {
+            filters.insert("label!", self.without_labels.clone());
+        }
+
+        serde_json::to_string(&filters)
+    }
+}
+
+/// PruneReport is what was removed by `prune_containers`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PruneReport {
+    #[serde(rename = "ContainersDeleted", deserialize_with = "null_as_default", default)]
+    pub containers_deleted: Vec<String>,
+    /// Disk space reclaimed in bytes.
+    #[serde(rename = "SpaceReclaimed", default)]
+    pub space_reclaimed: u64,
+}
@@ -0,0 +1,121 @@
+use std::{io::{ErrorKind, Read, Write}, net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream}, time::Duration};
+
+use regex::Regex;
+
+use crate::{container_inspect::InspectedContainer, container_ports::Protocol};
+
+/// Condition is what `wait_until` waits for a container to be ready
+#[derive(Debug, Clone)]
+pub enum Condition {
+    /// The container is running.
+    Running,
+    /// The health check of the container passes, the container must have a health check.
+    Healthy,
+    /// A line of the output of the container, stdout or stderr, matches the expression.
+    LogMatches(Regex),
+    /// The container accepts TCP connections on this port and keeps them open, on its address in one of its networks,
+    /// otherwise through its published port.
+    TcpPort(u16),
+    /// The command exits with code 0 when executed in the container.
+    ExecSucceeds(Vec<String>),
+}
+
+/// Returns the addresses to probe for a TCP port of the container, in the order they are tried.
+///
+/// The addresses of the container on its networks come first, sorted by network name: a published port is reached through
+/// the userland proxy of the Engine, which accepts connections as soon as the container starts, even when nothing listens yet.
+/// The published ports follow for the hosts that cannot reach the networks of the container, an unspecified address becomes the loopback address.
+pub fn tcp_addresses(inspected: &InspectedContainer, port: u16) -> Vec<SocketAddr> {
+    let mut networks: Vec<_> = inspected.network_settings.networks.iter().collect();
+    networks.sort_by_key(|(name, _)| name.to_string());
+    let mut addresses: Vec<SocketAddr> = networks.iter()
+        .filter_map(|(_, endpoint)| endpoint.ip_address.parse::<IpAddr>().ok())
+        .map(|ip| SocketAddr::new(ip, port))
+        .collect();
+
+    for address in inspected.network_settings.host_addresses(port, Protocol::Tcp) {
+        let ip = match address.ip() {
+            IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip => ip,
+        };
+        addresses.push(SocketAddr::new(ip, address.port()));
+    }
+
+    addresses
+}
+
+/// How long a connection must stay open, without data, for the port to be ready.
+const TCP_HOLD_TIME: Duration = Duration::from_millis(200);
+
+/// Returns true when a TCP connection to the address is accepted and held open, or the server sends data.
+///
+/// A connection closed or reset right after it is accepted is not ready, that is how the userland proxy answers when nothing listens in the container.
+pub fn probe_tcp(address: &SocketAddr, timeout: Duration) -> bool {
+    let mut stream = match TcpStream::connect_timeout(address, timeout) {
+        Ok(stream) => stream,
+        Err(_) => return false,
+    };
+    if stream.set_read_timeout(Some(TCP_HOLD_TIME)).is_err() {
+        return false;
+    }
+
+    let mut byte = [0u8; 1];
+    match stream.read(&mut byte) {
+        Ok(read) => read > 0,
+        Err(e) => matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut),
+    }
+}
+
+/// LineMatcher looks for a line matching an expression in what is written to it
+///
+/// It fails the write that completes the first matching line, so that a followed stream stops as soon as the line is found.
+pub struct LineMatcher<'a> {
+    regex: &'a Regex,
+    line: Vec<u8>,
+    pub matched: bool,
+}
+
+impl<'a> LineMatcher<'a> {
+    pub fn new(regex: &'a Regex) -> Self {
+        LineMatcher { regex, line: vec![], matched: false }
+    }
+
+    /// Checks the last line, which may not end with a new line.
+    pub fn finish(&mut self) -> bool {
+        if !self.matched && !self.line.is_empty() {
+            self.matched = self.regex.is_match(&String::from_utf8_lossy(&self.line));
+            self.line.clear();
+        }
+
+        self.matched
+    }
+}
+
+impl Write for LineMatcher<'_> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        if self.matched {
+            return Err(std::io::Error::other("log line matched"));
+        }
+
+        for byte in bytes {
+            if *byte != b'\n' {
+                self.line.push(*byte);
+                continue;
+            }
+
+            let line = String::from_utf8_lossy(&self.line).trim_end_matches('\r').to_string();
+            self.line.clear();
+            if self.regex.is_match(&line) {
+                self.matched = true;
+                return Err(std::io::Error::other("log line matched"));
+            }
+        }
+
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use crate::{container_structs::Container, error::{invalid, not_found}};
+
+/// ContainerReference is the way a container is identified by the caller, as accepted by the `docker` CLI
+///
+/// A reference starting with `label=` selects every container with the label (`label=key` or `label=key=value`),
+/// any other reference is a full id, a name (with or without the leading `/`) or a unique prefix of an id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerReference {
+    /// A full id, a name or an id prefix, tried in that order like the Engine does.
+    IdOrName(String),
+    /// A label key, with the value it should have if any.
+    Label(String, Option<String>),
+}
+
+impl FromStr for ContainerReference {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Some(selector) = value.strip_prefix("label=") {
+            let (key, label) = match selector.split_once('=') {
+                Some((key, label)) => (key, Some(label.to_string())),
+                None => (selector, None),
+            };
+            if key.is_empty() {
+                return Err(invalid(format!("invalid label selector: '{}'", value)));
+            }
+
+            return Ok(ContainerReference::Label(key.to_string(), label));
+        }
+
+        if value.is_empty() || value == "/" {
+            return Err(invalid("empty container reference".to_string()));
+        }
+
+        Ok(ContainerReference::IdOrName(value.to_string()))
+    }
+}
+
+impl ContainerReference {
+    /// Returns the containers matching the reference among `containers`.
+    ///
+    /// An id or name matches exactly one container; it is an error when nothing matches, or when an id prefix matches several containers.
+    /// A label selector matches any number of containers; it is an error when nothing matches.
+    pub fn resolve(&self, containers: &[Container]) -> Result<Vec<Container>, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            ContainerReference::IdOrName(reference) => {
+                if let Some(container) = containers.iter().find(|container| &container.id == reference) {
+                    return Ok(vec![container.clone()]);
+                }
+
+                let name = format!("/{}", reference.trim_start_matches('/'));
+                if let Some(container) = containers.iter().find(|container| container.names.contains(&name)) {
+                    return Ok(vec![container.clone()]);
+                }
+
+                let matches: Vec<&Container> = containers.iter().filter(|container| container.id.starts_with(reference.as_str())).collect();
+                match matches.len() {
+                    0 => Err(not_found(format!("no such container: '{}'", reference))),
+                    1 => Ok(vec![matches[0].clone()]),
+                    _ => {
+                        let candidates: Vec<String> = matches.iter().map(|container| describe(container)).collect();
+                        Err(invalid(format!("'{}' matches {} containers: {}", reference, matches.len(), candidates.join(", "))))
+                    }
+                }
+            },
+            ContainerReference::Label(key, value) => {
+                let matches: Vec<Container> = containers
+                    .iter()
+                    .filter(|container| match (container.labels.get(key), value) {
+                        (Some(label), Some(value)) => label == value,
+                        (Some(_), None) => true,
+                        (None, _) => false,
+                    })
+                    .cloned()
+                    .collect();
+
+                if matches.is_empty() {
+                    return Err(not_found(format!("no container matches '{}'", self)));
+                }
+
+                Ok(matches)
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ContainerReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerReference::IdOrName(reference) => write!(f, "{}", reference),
+            ContainerReference::Label(key, Some(value)) => write!(f, "label={}={}", key, value),
+            ContainerReference::Label(key, None) => write!(f, "label={}", key),
+        }
+    }
+}
+
+fn describe(container: &Container) -> String {
+    let id: String = container.id.chars().take(12).collect();
+    match container.names.first() {
+        Some(name) => format!("{} ({})", id, name.trim_start_matches('/')),
+        None => id,
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    api::EmptyOk,
+    container_host_config::HostConfig,
+    container_update::UpdateContainerWith,
+    units::{nano_cpus, parse_bytes, parse_cpuset},
+    error::invalid,
+};
+
+/// The smallest memory limit accepted by the Engine.
+pub const MIN_MEMORY: i64 = 6 * 1024 * 1024;
+
+/// Resources are the memory and CPU limits of a container in Docker CLI notation
+///
+/// They are converted into the fields of `UpdateContainerWith` or of the host config of a new container,
+/// after checking that the limits can be applied together.
+///
+/// ```rust,ignore
+/// use docker_engine_api::container_resources::Resources;
+///
+/// let resources = Resources {
+///     memory: Some("512m".to_string()),
+///     memory_swap: Some("1g".to_string()),
+///     cpus: Some(1.5),
+///     cpuset_cpus: Some("0-3".to_string()),
+///     ..Default::default()
+/// };
+/// client.containers.update_container(&id, &resources.to_update()?)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Resources {
+    /// Memory limit, as `512m` or `2g`, at least 6MB.
+    pub memory: Option<String>,
+    /// Soft memory limit, as `256m`, at most the memory limit.
+    pub memory_reservation: Option<String>,
+    /// Total memory limit (memory + swap), as `1g`, at least the memory limit and only with one, or `-1` for unlimited swap.
+    pub memory_swap: Option<String>,
+    /// Number of CPUs, as `1.5`.
+    pub cpus: Option<f64>,
+    /// CPU CFS period in microseconds, between 1000 and 1000000.
+    /// When it is set, `cpus` is applied as a CPU quota over this period instead of `NanoCpus`.
+    pub cpu_period: Option<i64>,
+    /// CPUs in which to allow execution, as `0-3` or `0,1`.
+    pub cpuset_cpus: Option<String>,
+    /// Memory nodes in which to allow execution, as `0-3` or `0,1`.
+    pub cpuset_mems: Option<String>,
+}
+
+/// The limits of `Resources` in the units of the Engine.
+#[derive(Debug, Default)]
+struct Limits {
+    memory: Option<i64>,
+    memory_reservation: Option<i64>,
+    memory_swap: Option<i64>,
+    nano_cpus: Option<i64>,
+    cpu_quota: Option<i64>,
+    cpu_period: Option<i64>,
+    cpuset_cpus: Option<String>,
+    cpuset_mems: Option<String>,
+}
+
+impl Resources {
+    /// Converts the resources into the options of `update_container`.
+    pub fn to_update(&self) -> Result<UpdateContainerWith, Box<dyn std::error::Error + Send + Sync>> {
+        let limits = self.limits()?;
+        Ok(UpdateContainerWith {
+            memory: limits.memory,
+            memory_reservation: limits.memory_reservation,
+            memory_swap: limits.memory_swap,
+            nano_cpus: limits.nano_cpus,
+            cpu_quota: limits.cpu_quota,
+            cpu_period: limits.cpu_period,
+            cpuset_cpus: limits.cpuset_cpus,
+            cpuset_mems: limits.cpuset_mems,
+            ..Default::default()
+        })
+    }
+
+    /// Sets the resources in the host config of a new container, the CPU limit replaces the one already set.
+    ///
+    /// The memory limits are checked against those already in the host config, which is left unchanged when they cannot be applied together.
+    pub fn apply(&self, host_config: &mut HostConfig) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let limits = self.limits()?;
+        check_memory(
+            limits.memory.or(host_config.memory),
+            limits.memory_reservation.or(host_config.memory_reservation),
+            limits.memory_swap.or(host_config.memory_swap),
+        )?;
+
+        if limits.nano_cpus.is_some() || limits.cpu_quota.is_some() {
+            // The Engine refuses a limit set both in NanoCpus and as a quota
+            host_config.nano_cpus = limits.nano_cpus;
+            host_config.cpu_quota = limits.cpu_quota;
+            host_config.cpu_period = limits.cpu_period;
+        } else if limits.cpu_period.is_some() {
+            host_config.cpu_period = limits.cpu_period;
+        }
+
+        host_config.memory = limits.memory.or(host_config.memory);
+        host_config.memory_reservation = limits.memory_reservation.or(host_config.memory_reservation);
+        host_config.memory_swap = limits.memory_swap.or(host_config.memory_swap);
+        host_config.cpuset_cpus = limits.cpuset_cpus.or(host_config.cpuset_cpus.take());
+        host_config.cpuset_mems = limits.cpuset_mems.or(host_config.cpuset_mems.take());
+        Ok(())
+    }
+
+    fn limits(&self) -> Result<Limits, Box<dyn std::error::Error + Send + Sync>> {
+        let mut limits = Limits {
+            memory: self.memory.as_deref().map(parse_bytes).transpose()?,
+            memory_reservation: self.memory_reservation.as_deref().map(parse_bytes).transpose()?,
+            memory_swap: match self.memory_swap.as_deref().map(str::trim) {
+                Some("-1") => Some(-1),
+                Some(memory_swap) => Some(parse_bytes(memory_swap)?),
+                None => None,
+            },
+            cpu_period: self.cpu_period,
+            ..Default::default()
+        };
+
+        if let Some(memory) = limits.memory {
+            if memory < MIN_MEMORY {
+                return Err(invalid(format!("the memory limit must be at least 6MB, got {} bytes", memory)));
+            }
+
+            // Without a memory limit here, the container may already have one when updated
+            check_memory(Some(memory), limits.memory_reservation, limits.memory_swap)?;
+        }
+
+        if let Some(cpu_period) = self.cpu_period {
+            if !(1000..=1_000_000).contains(&cpu_period) {
+                return Err(invalid(format!("the CPU period must be between 1000 and 1000000 microseconds, got {}", cpu_period)));
+            }
+        }
+
+        if let Some(cpus) = self.cpus {
+            let nano_cpus = nano_cpus(cpus)?;
+            match self.cpu_period {
+                None => limits.nano_cpus = Some(nano_cpus),
+                Some(cpu_period) => {
+                    let cpu_quota = (nano_cpus as i128 * cpu_period as i128 / 1_000_000_000) as i64;
+                    if cpu_quota < 1000 {
+                        return Err(invalid(format!("{} CPUs over a period of {} microseconds is a CPU quota below the minimum of 1000", cpus, cpu_period)));
+                    }
+                    limits.cpu_quota = Some(cpu_quota);
+                },
+            }
+        }
+
+        for (cpuset, limit) in [(&self.cpuset_cpus, &mut limits.cpuset_cpus), (&self.cpuset_mems, &mut limits.cpuset_mems)] {
+            if let Some(cpuset) = cpuset {
+                parse_cpuset(cpuset)?;
+                *limit = Some(cpuset.trim().to_string());
+            }
+        }
+
+        Ok(limits)
+    }
+}
+
+/// Checks the memory reservation and swap limits against the memory limit the way the Engine does, 0 meaning no memory limit.
+fn check_memory(memory: Option<i64>, memory_reservation: Option<i64>, memory_swap: Option<i64>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+    let memory = match memory.filter(|memory| *memory > 0) {
+        Some(memory) => memory,
+        None => {
+            if let Some(memory_swap) = memory_swap.filter(|memory_swap| *memory_swap > 0) {
+                return Err(invalid(format!("a memory swap limit ({} bytes) requires a memory limit", memory_swap)));
+            }
+
+            return Ok(());
+        },
+    };
+
+    if let Some(memory_swap) = memory_swap.filter(|memory_swap| *memory_swap > 0 && *memory_swap < memory) {
+        return Err(invalid(format!("the memory swap limit ({} bytes) must be at least the memory limit ({} bytes)", memory_swap, memory)));
+    }
+
+    if let Some(memory_reservation) = memory_reservation.filter(|memory_reservation| *memory_reservation > memory) {
+        return Err(invalid(format!("the memory reservation ({} bytes) must be at most the memory limit ({} bytes)", memory_reservation, memory)));
+    }
+
+    Ok(())
+}
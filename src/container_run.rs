@@ -0,0 +1,45 @@
+/// PullPolicy is when `run_container` should pull the image of the container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PullPolicy {
+    /// Pull the image only when it is not present locally.
+    #[default]
+    Missing,
+    /// Always pull the image, to get the latest version of its tag.
+    Always,
+    /// Never pull the image, fail if it is not present locally.
+    Never,
+}
+
+/// RunOptions are the options of `run_container` that are not part of the container configuration
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// The name of the container, the Engine generates one when empty.
+    pub name: String,
+    /// The platform of the image, such as `linux/amd64`, the Engine default when empty.
+    pub platform: String,
+    pub pull: PullPolicy,
+    /// Remove the container once it exits, or if anything fails after it was created, like `docker run --rm`.
+    pub remove: bool,
+}
+
+/// RunOutput is the result of a container run by `run_container`
+#[derive(Debug, Clone, Default)]
+pub struct RunOutput {
+    /// The id of the container, already removed when `RunOptions.remove` is set.
+    pub id: String,
+    pub exit_code: i64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Returns the image reference to pull, with the `latest` tag when it has neither a tag nor a digest.
+///
+/// The Engine pulls every tag of the repository when the tag is missing, which is never what `run` means.
+pub fn pull_reference(image: &str) -> String {
+    let name = image.rsplit('/').next().unwrap_or(image);
+    if name.contains(':') || name.contains('@') {
+        return image.to_string();
+    }
+
+    format!("{}:latest", image)
+}
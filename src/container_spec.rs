@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use crate::{
+    container_create::CreateContainerFrom,
+    container_host_config::{HostConfig, PortBinding, RestartPolicy},
+    container_network::{EndpointSettings, NetworkingConfig},
+    container_health::HealthConfig,
+    signal::Signal,
+    container_ports::{Protocol, PortSpec, port_key},
+    units::parse_bytes,
+    container_resources::Resources,
+    container_mounts::{VolumeSpec, parse_mount},
+    container_devices::validate_device_cgroup_rule,
+    container_structs::GenericDevice,
+    error::invalid,
+};
+
+/// ContainerSpec is a builder for `CreateContainerFrom`, including its host and networking configuration
+///
+/// ```rust,ignore
+/// use docker_engine_api::container_spec::ContainerSpec;
+/// use docker_engine_api::container_ports::Protocol;
+/// use docker_engine_api::container_host_config::RestartPolicy;
+///
+/// let options = ContainerSpec::new()
+///     .image("nginx:alpine")
+///     .env("MODE", "production")
+///     .label("team", "web")
+///     .expose(443, Protocol::Tcp)
+///     .publish(8080, 80)
+///     .bind("/srv/www", "/usr/share/nginx/html", true)
+///     .memory("512m")
+///     .restart(RestartPolicy::OnFailure(3))
+///     .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct ContainerSpec {
+    config: CreateContainerFrom,
+    host_config: HostConfig,
+    networking_config: NetworkingConfig,
+    error: Option<String>,
+}
+
+impl ContainerSpec {
+    pub fn new() -> Self {
+        ContainerSpec::default()
+    }
+
+    pub fn image(mut self, image: &str) -> Self {
+        self.config.image = Some(image.to_string());
+        self
+    }
+
+    pub fn cmd(mut self, cmd: &[&str]) -> Self {
+        self.config.cmd = Some(cmd.iter().map(|arg| arg.to_string()).collect());
+        self
+    }
+
+    pub fn entrypoint(mut self, entrypoint: &[&str]) -> Self {
+        self.config.entrypoint = Some(entrypoint.iter().map(|arg| arg.to_string()).collect());
+        self
+    }
+
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.config.env.get_or_insert_with(Vec::new).push(format!("{}={}", key, value));
+        self
+    }
+
+    pub fn label(mut self, key: &str, value: &str) -> Self {
+        self.config.labels.get_or_insert_with(HashMap::new).insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn hostname(mut self, hostname: &str) -> Self {
+        self.config.hostname = Some(hostname.to_string());
+        self
+    }
+
+    pub fn user(mut self, user: &str) -> Self {
+        self.config.user = Some(user.to_string());
+        self
+    }
+
+    pub fn working_dir(mut self, working_dir: &str) -> Self {
+        self.config.working_dir = Some(working_dir.to_string());
+        self
+    }
+
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.config.tty = Some(tty);
+        self
+    }
+
+    /// Exposes a container port without publishing it on the host.
+    pub fn expose(mut self, port: u16, protocol: Protocol) -> Self {
+        self.config.exposed_ports.get_or_insert_with(HashMap::new).insert(port_key(port, protocol), ());
+        self
+    }
+
+    /// Publishes a TCP container port on a host port of all the host interfaces, `0` picks a random host port.
+    pub fn publish(self, host_port: u16, container_port: u16) -> Self {
+        self.publish_on("", host_port, container_port, Protocol::Tcp)
+    }
+
+    /// Publishes a container port on a host port of the given host IP, `0` picks a random host port.
+    pub fn publish_on(mut self, host_ip: &str, host_port: u16, container_port: u16, protocol: Protocol) -> Self {
+        self = self.expose(container_port, protocol);
+
+        let binding = PortBinding {
+            host_ip: host_ip.to_string(),
+            host_port: if host_port == 0 { String::new() } else { host_port.to_string() },
+        };
+
+        self.host_config.port_bindings.get_or_insert_with(HashMap::new)
+            .entry(port_key(container_port, protocol))
+            .or_default()
+            .push(binding);
+        self
+    }
+
+    /// Publishes ports in `docker run -p` notation, e.g. `8080:80`, `127.0.0.1::53/udp` or `3000-3005:3000-3005`.
+    pub fn publish_spec(mut self, spec: &str) -> Self {
+        let spec: PortSpec = match spec.parse() {
+            Ok(spec) => spec,
+            Err(e) => {
+                self.fail(e.to_string());
+                return self;
+            },
+        };
+
+        for key in spec.exposed_ports() {
+            self.config.exposed_ports.get_or_insert_with(HashMap::new).insert(key, ());
+        }
+        for (key, binding) in spec.port_bindings() {
+            self.host_config.port_bindings.get_or_insert_with(HashMap::new).entry(key).or_default().push(binding);
+        }
+        self
+    }
+
+    /// Bind mounts a host path into the container.
+    pub fn bind(mut self, source: &str, destination: &str, read_only: bool) -> Self {
+        let bind = if read_only { format!("{}:{}:ro", source, destination) } else { format!("{}:{}", source, destination) };
+        self.host_config.binds.get_or_insert_with(Vec::new).push(bind);
+        self
+    }
+
+    /// Mounts a volume or a host path in `docker run -v` notation, e.g. `/srv:/data:ro,z` or `pgdata:/var/lib/postgresql/data`.
+    ///
+    /// Like `docker run -v`, a missing host path is created, and an anonymous volume is created when only the target is given.
+    pub fn volume(mut self, spec: &str) -> Self {
+        match spec.parse::<VolumeSpec>() {
+            Ok(spec) => match spec.bind() {
+                Some(bind) => self.host_config.binds.get_or_insert_with(Vec::new).push(bind),
+                None => self.host_config.mounts.get_or_insert_with(Vec::new).push(spec.mount),
+            },
+            Err(e) => self.fail(e.to_string()),
+        }
+        self
+    }
+
+    /// Adds a mount in `docker run --mount` notation, e.g. `type=tmpfs,target=/cache,tmpfs-size=64m`.
+    pub fn mount(mut self, spec: &str) -> Self {
+        match parse_mount(spec) {
+            Ok(mount) => self.host_config.mounts.get_or_insert_with(Vec::new).push(mount),
+            Err(e) => self.fail(e.to_string()),
+        }
+        self
+    }
+
+    /// Maps a host device in `docker run --device` notation, e.g. `/dev/sdc:/dev/xvdc:rwm`.
+    pub fn device(mut self, spec: &str) -> Self {
+        match spec.parse::<GenericDevice>() {
+            Ok(device) => self.host_config.devices.get_or_insert_with(Vec::new).push(device),
+            Err(e) => self.fail(e.to_string()),
+        }
+        self
+    }
+
+    /// Adds a rule to the cgroup allowed devices list, e.g. `c 1:3 mr`.
+    pub fn device_cgroup_rule(mut self, rule: &str) -> Self {
+        match validate_device_cgroup_rule(rule) {
+            Ok(()) => self.host_config.device_cgroup_rules.get_or_insert_with(Vec::new).push(rule.to_string()),
+            Err(e) => self.fail(e.to_string()),
+        }
+        self
+    }
+
+    /// Sets the memory limit in Docker CLI notation, e.g. `512m` or `2g`.
+    pub fn memory(mut self, memory: &str) -> Self {
+        match parse_bytes(memory) {
+            Ok(bytes) => self.host_config.memory = Some(bytes),
+            Err(e) => self.fail(e.to_string()),
+        }
+        self
+    }
+
+    /// Sets the memory and CPU limits, checking that they can be applied together.
+    pub fn resources(mut self, resources: &Resources) -> Self {
+        if let Err(e) = resources.apply(&mut self.host_config) {
+            self.fail(e.to_string());
+        }
+        self
+    }
+
+    pub fn healthcheck(mut self, healthcheck: HealthConfig) -> Self {
+        if let Err(e) = healthcheck.validate() {
+            self.fail(e.to_string());
+        }
+        self.config.healthcheck = Some(healthcheck);
+        self
+    }
+
+    pub fn stop_signal(mut self, signal: Signal) -> Self {
+        self.config.stop_signal = Some(signal);
+        self
+    }
+
+    pub fn restart(mut self, policy: RestartPolicy) -> Self {
+        self.host_config.restart_policy = Some(policy);
+        self
+    }
+
+    pub fn auto_remove(mut self, auto_remove: bool) -> Self {
+        self.host_config.auto_remove = Some(auto_remove);
+        self
+    }
+
+    /// Connects the container to a network at creation.
+    pub fn network(mut self, network: &str) -> Self {
+        self.networking_config.endpoints_config.insert(network.to_string(), EndpointSettings::default());
+        self
+    }
+
+    /// Gives access to the host config for the settings without a dedicated method.
+    pub fn with_host_config<F: FnOnce(&mut HostConfig)>(mut self, configure: F) -> Self {
+        configure(&mut self.host_config);
+        self
+    }
+
+    /// Builds the options for `create_container`, or returns the first invalid value given to the builder.
+    pub fn build(self) -> Result<CreateContainerFrom, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(error) = self.error {
+            return Err(invalid(error));
+        }
+
+        let mut config = self.config;
+        config.host_config = Some(self.host_config);
+        if !self.networking_config.endpoints_config.is_empty() {
+            config.networking_config = Some(self.networking_config);
+        }
+
+        Ok(config)
+    }
+
+    fn fail(&mut self, error: String) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+}
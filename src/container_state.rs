@@ -0,0 +1,173 @@
+use serde::{Serialize, Deserialize};
+use std::{fmt, convert::Infallible, str::FromStr};
+
+/// ContainerState is the state of a container, as reported when listing or inspecting it
+///
+/// States added by newer Engines are kept as `Unknown` instead of failing to deserialize.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum ContainerState {
+    Created,
+    Running,
+    Paused,
+    Restarting,
+    Removing,
+    Exited,
+    Dead,
+    Unknown(String),
+}
+
+impl ContainerState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContainerState::Created => "created",
+            ContainerState::Running => "running",
+            ContainerState::Paused => "paused",
+            ContainerState::Restarting => "restarting",
+            ContainerState::Removing => "removing",
+            ContainerState::Exited => "exited",
+            ContainerState::Dead => "dead",
+            ContainerState::Unknown(state) => state,
+        }
+    }
+
+    /// Returns true when the container has a running process, that is when it is running, paused or restarting.
+    pub fn is_running(&self) -> bool {
+        matches!(self, ContainerState::Running | ContainerState::Paused | ContainerState::Restarting)
+    }
+}
+
+impl Default for ContainerState {
+    fn default() -> Self {
+        ContainerState::Unknown(String::new())
+    }
+}
+
+impl From<String> for ContainerState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "created" => ContainerState::Created,
+            "running" => ContainerState::Running,
+            "paused" => ContainerState::Paused,
+            "restarting" => ContainerState::Restarting,
+            "removing" => ContainerState::Removing,
+            "exited" => ContainerState::Exited,
+            "dead" => ContainerState::Dead,
+            _ => ContainerState::Unknown(state),
+        }
+    }
+}
+
+impl From<ContainerState> for String {
+    fn from(state: ContainerState) -> Self {
+        match state {
+            ContainerState::Unknown(state) => state,
+            state => state.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for ContainerState {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(ContainerState::from(value.to_string()))
+    }
+}
+
+impl fmt::Display for ContainerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// HealthStatus is the result of the health check of a container
+///
+/// Statuses added by newer Engines are kept as `Unknown` instead of failing to deserialize.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(from = "String", into = "String")]
+pub enum HealthStatus {
+    /// The container has no health check.
+    #[default]
+    None,
+    /// The container is in its start period, or no check has completed yet.
+    Starting,
+    Healthy,
+    Unhealthy,
+    Unknown(String),
+}
+
+impl HealthStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HealthStatus::None => "none",
+            HealthStatus::Starting => "starting",
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Unhealthy => "unhealthy",
+            HealthStatus::Unknown(status) => status,
+        }
+    }
+}
+
+impl From<String> for HealthStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "none" | "" => HealthStatus::None,
+            "starting" => HealthStatus::Starting,
+            "healthy" => HealthStatus::Healthy,
+            "unhealthy" => HealthStatus::Unhealthy,
+            _ => HealthStatus::Unknown(status),
+        }
+    }
+}
+
+impl From<HealthStatus> for String {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Unknown(status) => status,
+            status => status.as_str().to_string(),
+        }
+    }
+}
+
+impl FromStr for HealthStatus {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(HealthStatus::from(value.to_string()))
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// HealthLogEntry is the result of one run of the health check of a container
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct HealthLogEntry {
+    #[serde(rename = "Start", default)]
+    pub start: String,
+    #[serde(rename = "End", default)]
+    pub end: String,
+    /// The exit code of the check: 0 is healthy, 1 is unhealthy, any other value means the check could not run.
+    #[serde(rename = "ExitCode", default)]
+    pub exit_code: i64,
+    /// The output of the check, truncated by the Engine.
+    #[serde(rename = "Output", default)]
+    pub output: String,
+}
+
+#[cfg(feature = "chrono")]
+impl HealthLogEntry {
+    /// Returns when the check started, `None` when it is not reported or cannot be parsed.
+    pub fn start_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.start).ok().flatten()
+    }
+
+    /// Returns when the check ended, `None` when it is not reported or cannot be parsed.
+    pub fn end_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.end).ok().flatten()
+    }
+}
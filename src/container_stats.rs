@@ -19,6 +19,14 @@ pub struct Stats {
     pub precpu_stats: CpuStats,
 }
 
+#[cfg(feature = "chrono")]
+impl Stats {
+    /// Returns when the stats were read, `None` when it is not reported or cannot be parsed.
+    pub fn read_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse(&self.read).ok().flatten()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct PidsStats {
     #[serde(rename(deserialize = "current"), default)]
@@ -1,6 +1,7 @@
 use serde::{Serialize, Deserialize};
 use std::{collections::HashMap};
 use crate::container_network::{HostConfig, NetworkSettings};
+use crate::container_state::{ContainerState, HealthStatus};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Port {
@@ -49,7 +50,8 @@ pub struct Container {
     #[serde(rename = "Created", default)]
     pub created: u64,
     #[serde(rename = "State", default)]
-    pub state: String,
+    pub state: ContainerState,
+    /// A human readable status, such as `Up 2 minutes (healthy)` or `Exited (0) 5 seconds ago`.
     #[serde(rename = "Status", default)]
     pub status: String,
     #[serde(rename = "Ports", default)]
@@ -77,7 +79,7 @@ impl Default for Container {
             image_id: String::new(),
             command: String::new(),
             created: 0,
-            state: String::new(),
+            state: ContainerState::default(),
             status: String::new(),
             ports: Vec::new(),
             labels: HashMap::new(),
@@ -86,9 +88,7 @@ impl Default for Container {
             host_config: HostConfig {
                 network_mode: String::new(),
             },
-            network_settings: NetworkSettings {
-                networks: HashMap::new(),
-            },
+            network_settings: NetworkSettings::default(),
             mounts: Vec::new(),
         }
     }
@@ -107,8 +107,8 @@ impl Clone for Container {
             status: self.status.clone(),
             ports: self.ports.clone(),
             labels: self.labels.clone(),
-            size_rw: self.size_rw.clone(),
-            size_root_fs: self.size_root_fs.clone(),
+            size_rw: self.size_rw,
+            size_root_fs: self.size_root_fs,
             host_config: self.host_config.clone(),
             network_settings: self.network_settings.clone(),
             mounts: self.mounts.clone(),
@@ -116,14 +116,42 @@ impl Clone for Container {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+impl Container {
+    /// Returns the creation time of the container, `None` when it is not reported.
+    #[cfg(feature = "chrono")]
+    pub fn created_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::from_unix(self.created)
+    }
+
+    /// Returns the health of the container, as reported at the end of its status.
+    ///
+    /// Listing containers does not report the health on its own, so it is read from the status (`Up 2 minutes (healthy)`).
+    pub fn health(&self) -> HealthStatus {
+        let status = self.status.trim_end();
+        if status.ends_with("(health: starting)") {
+            return HealthStatus::Starting;
+        }
+        if status.ends_with("(unhealthy)") {
+            return HealthStatus::Unhealthy;
+        }
+        if status.ends_with("(healthy)") {
+            return HealthStatus::Healthy;
+        }
+
+        HealthStatus::None
+    }
+}
+
+/// GenericDevice is a host device mapped into a container, see `container_devices` to parse it from `--device` notation
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct GenericDevice {
     #[serde(rename = "PathOnHost", default)]
     pub host_path: String,
     #[serde(rename = "PathInContainer", default)]
     pub container_path: String,
+    /// The access allowed to the device, a combination of `r` (read), `w` (write) and `m` (mknod), e.g. `rwm`.
     #[serde(rename = "CgroupPermissions", default)]
-    pub options: HashMap<String, String>,
+    pub cgroup_permissions: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -132,5 +160,5 @@ pub struct WarningsResponse {
     pub warnings: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct EmptyMap {}
@@ -0,0 +1,48 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+/// WaitCondition is the state a container should reach for `wait_container` to return
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WaitCondition {
+    /// Wait until the container is not running, returns immediately if it is already stopped.
+    #[default]
+    #[serde(rename = "not-running")]
+    NotRunning,
+    /// Wait until the container exits the next time, even if it is stopped right now.
+    #[serde(rename = "next-exit")]
+    NextExit,
+    /// Wait until the container is removed.
+    #[serde(rename = "removed")]
+    Removed,
+}
+
+impl WaitCondition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaitCondition::NotRunning => "not-running",
+            WaitCondition::NextExit => "next-exit",
+            WaitCondition::Removed => "removed",
+        }
+    }
+}
+
+impl fmt::Display for WaitCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WaitExitError {
+    #[serde(rename = "Message", default)]
+    pub message: String,
+}
+
+/// WaitResponse is the exit status of a container once the wait condition is met
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct WaitResponse {
+    #[serde(rename = "StatusCode", default)]
+    pub status_code: i64,
+    #[serde(rename = "Error", skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<WaitExitError>,
+}
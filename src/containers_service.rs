@@ -34,6 +34,22 @@ use crate::{api::{
     GET_CONTAINER_LOGS_METHOD,
     GetContainerLogsReturn,
 
+    GET_CHANGES_CONTAINER_START,
+    GET_CHANGES_CONTAINER_END,
+    GET_CHANGES_CONTAINER_METHOD,
+    GetContainerChangesReturn,
+
+    EXPORT_CONTAINER_START,
+    EXPORT_CONTAINER_END,
+    EXPORT_CONTAINER_METHOD,
+
+    ARCHIVE_CONTAINER_START,
+    ARCHIVE_CONTAINER_END,
+    GET_ARCHIVE_CONTAINER_METHOD,
+    STAT_ARCHIVE_CONTAINER_METHOD,
+    PUT_ARCHIVE_CONTAINER_METHOD,
+    ArchiveContainerReturn,
+
     LIST_PROCESSES_START,
     LIST_PROCESSES_END,
     LIST_PROCESSES_METHOD,
@@ -62,6 +78,7 @@ use crate::{api::{
     WAIT_CONTAINER_START,
     WAIT_CONTAINER_END,
     WAIT_CONTAINER_METHOD,
+    WaitContainerReturn,
 
     UPDATE_CONTAINER_START,
     UPDATE_CONTAINER_END,
@@ -73,9 +90,22 @@ use crate::{api::{
 
     DELETE_STOPPED_CONTAINERS,
     DELETE_STOPPED_CONTAINERS_METHOD,
-}, container_create::{CreateContainerFrom}, request::request, error::err, container_inspect::InspectedContainer, container_update::UpdateContainerWith, container_structs::WarningsResponse};
+    DeleteStoppedContainersReturn,
+
+    RunContainerReturn,
+
+    INSPECT_IMAGE_START,
+    INSPECT_IMAGE_END,
+    INSPECT_IMAGE_METHOD,
+
+    PULL_IMAGE,
+    PULL_IMAGE_METHOD,
+}, container_create::{CreateContainerFrom}, request::{request, request_async, request_timeout, request_streamed, request_streamed_async, request_upload, escape}, container_archive::{PathStat, PATH_STAT_HEADER, archive_host_path, extract_archive}, error::{err, invalid, not_found}, container_resolve::ContainerReference, container_filters::ContainerFilters, container_bulk::{BulkReport, BulkResult}, container_run::{PullPolicy, RunOptions, RunOutput, pull_reference}, stream::{frames, Demultiplexer}, container_structs::Container, container_wait::WaitCondition, container_prune::PruneFilters, signal::Signal, container_inspect::InspectedContainer, container_update::UpdateContainerWith, container_structs::WarningsResponse, container_devices::validate_devices};
+
+use std::{path::Path, sync::Arc, time::Duration};
 
-use hyper::{Client as HyperClient};
+use hyper::{Client as HyperClient, Method};
+use tokio::sync::Semaphore;
 use hyperlocal::{UnixConnector};
 
 pub struct ContainersService {
@@ -85,24 +115,41 @@ pub struct ContainersService {
 }
 
 pub trait ContainersServiceTrait {
-    fn list_containers(&mut self, all: bool, limit: i32, size: bool, filters: String) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn list_containers(&mut self, all: bool, limit: i32, size: bool, filters: &ContainerFilters) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn resolve_containers(&mut self, reference: &str) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn resolve_container(&mut self, reference: &str) -> Result<Container, Box<dyn std::error::Error + Send + Sync>>;
     fn create_container(&mut self, name: &str, image: &str, more: &CreateContainerFrom) -> Result<CreateContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
     fn inspect_container(&mut self, id: &str, size: bool) -> Result<InspectedContainer, Box<dyn std::error::Error + Send + Sync>>;
     fn start_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
-    fn stop_container(&mut self, id: &str, timeout: i32) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
-    fn restart_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
-    fn kill_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn stop_container(&mut self, id: &str, signal: Option<Signal>, timeout: Option<i32>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn restart_container(&mut self, id: &str, signal: Option<Signal>, timeout: Option<i32>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn kill_container(&mut self, id: &str, signal: Option<Signal>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
     fn remove_container(&mut self, id: &str, remove_associated_volumes: bool, force: bool, remove_specified_linked: bool) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
     fn get_container_logs(&mut self, id: &str) -> Result<GetContainerLogsReturn, Box<dyn std::error::Error + Send + Sync>>;
-    fn list_processes(&mut self, id: &str) -> Result<ListProcessesReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn get_container_changes(&mut self, id: &str) -> Result<GetContainerChangesReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn export_container(&mut self, id: &str, writer: &mut dyn std::io::Write) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn get_container_archive(&mut self, id: &str, path: &str, writer: &mut dyn std::io::Write) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn stat_container_path(&mut self, id: &str, path: &str) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn put_container_archive(&mut self, id: &str, path: &str, reader: &mut dyn std::io::Read, no_overwrite_dir_non_dir: bool, copy_uid_gid: bool) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn copy_to_container(&mut self, id: &str, host_path: &Path, container_dir: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn copy_from_container(&mut self, id: &str, container_path: &str, host_dir: &Path) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn list_processes(&mut self, id: &str, ps_args: Option<&str>) -> Result<ListProcessesReturn, Box<dyn std::error::Error + Send + Sync>>;
     fn get_stats_container(&mut self, id: &str, stream: bool, oneshot: bool) -> Result<GetContainerStatsReturn, Box<dyn std::error::Error + Send + Sync>>;
     fn resize_container_tty(&mut self, id: &str, height: i32, width: i32) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
     fn pause_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
     fn unpause_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
-    fn wait_container(&mut self, id: &str, condition: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn wait_container(&mut self, id: &str, condition: WaitCondition) -> Result<WaitContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn wait_container_timeout(&mut self, id: &str, condition: WaitCondition, timeout: Duration) -> Result<WaitContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
     fn update_container(&mut self, id: &str, more: &UpdateContainerWith) -> Result<WarningsResponse, Box<dyn std::error::Error + Send + Sync>>;
     fn rename_container(&mut self, id: &str, new_name: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
-    fn delete_stopped_containers(self) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn delete_stopped_containers(&mut self) -> Result<DeleteStoppedContainersReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn pull_image(&mut self, image: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn stop_all(&mut self, filters: &ContainerFilters, timeout: Option<i32>, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>>;
+    fn restart_all(&mut self, filters: &ContainerFilters, timeout: Option<i32>, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>>;
+    fn remove_all(&mut self, filters: &ContainerFilters, remove_associated_volumes: bool, force: bool, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>>;
+    fn run_container(&mut self, config: &CreateContainerFrom, options: &RunOptions) -> Result<RunContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn run_container_streamed(&mut self, config: &CreateContainerFrom, options: &RunOptions, stdout: &mut dyn std::io::Write, stderr: &mut dyn std::io::Write) -> Result<RunContainerReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn prune_containers(&mut self, filters: &PruneFilters) -> Result<DeleteStoppedContainersReturn, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 impl ContainersServiceTrait for ContainersService {
@@ -113,15 +160,15 @@ impl ContainersServiceTrait for ContainersService {
     /// * `all` - A boolean value indicating whether to show all containers or only running containers.
     /// * `limit` - An integer value indicating the maximum number of containers to return.
     /// * `size` - A boolean value indicating whether to show the container size in human readable format.
-    /// * `filters` - A string value containing filters to apply to the container list.
+    /// * `filters` - The filters selecting the containers to list.
     /// 
     ///  See the [More information](https://docs.docker.com/engine/api/v1.41/#tag/Container) for more information.
     /// 
     /// # Returns
     ///
     /// Returns a `Result` containing a vector of `Container` structs on success, or an error of type `Box<dyn std::error::Error + Send + Sync>` on failure.
-    fn list_containers(&mut self, all: bool, limit: i32, size: bool, filters: String) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}?all={}&limit={}&size={}&filters={}", LIST_CONTAINERS, all, limit, size, filters);
+    fn list_containers(&mut self, all: bool, limit: i32, size: bool, filters: &ContainerFilters) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}?all={}&limit={}&size={}&filters={}", LIST_CONTAINERS, all, limit, size, escape(&filters.to_json()?));
         let response = request(&self.client, self.url.clone(), url, LIST_CONTAINERS_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -131,12 +178,48 @@ impl ContainersServiceTrait for ContainersService {
 
                 let containers = serde_json::from_slice(&r.body)?;
 
-                return Ok(containers)
+                Ok(containers)
             },
             Err(e) => Err(e),
         }
     }
 
+    /// Finds the containers matching a reference, as the `docker` CLI accepts them.
+    ///
+    /// # Arguments
+    ///
+    /// * reference - A full id, a unique id prefix, a name with or without the leading `/`, or a label selector (`label=key` or `label=key=value`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the matching containers, stopped ones included, on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// The error is of kind `NotFound` when nothing matches, and of kind `InvalidInput` when an id prefix matches several containers.
+    fn resolve_containers(&mut self, reference: &str) -> Result<ListContainersReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let reference: ContainerReference = reference.parse()?;
+        let containers = self.list_containers(true, 0, false, &ContainerFilters::default())?;
+
+        reference.resolve(&containers)
+    }
+
+    /// Finds the only container matching a reference, as the `docker` CLI accepts them.
+    ///
+    /// # Arguments
+    ///
+    /// * reference - A full id, a unique id prefix, a name with or without the leading `/`, or a label selector (`label=key` or `label=key=value`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the matching container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// The error is of kind `InvalidInput` when the reference matches several containers.
+    fn resolve_container(&mut self, reference: &str) -> Result<Container, Box<dyn std::error::Error + Send + Sync>> {
+        let mut containers = self.resolve_containers(reference)?;
+        if containers.len() > 1 {
+            return Err(invalid(format!("'{}' matches {} containers", reference, containers.len())));
+        }
+
+        Ok(containers.remove(0))
+    }
+
     /// Creates a new container.
     ///
     /// # Arguments
@@ -151,6 +234,13 @@ impl ContainersServiceTrait for ContainersService {
     ///
     /// Returns a Result containing the details of the created container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     fn create_container(&mut self, name: &str, platform: &str, more: &CreateContainerFrom) -> Result<CreateContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(healthcheck) = &more.healthcheck {
+            healthcheck.validate()?;
+        }
+        if let Some(host_config) = &more.host_config {
+            validate_devices(host_config.devices.as_deref().unwrap_or_default(), host_config.device_cgroup_rules.as_deref().unwrap_or_default())?;
+        }
+
         let url = format!("{}?name={}&platform={}", CREATE_CONTAINER, name, platform);
         let body = serde_json::to_string(&more)?;
         let response = request(&self.client, self.url.clone(), url, CREATE_CONTAINER_METHOD, body, &self.runtime);
@@ -162,7 +252,7 @@ impl ContainersServiceTrait for ContainersService {
 
                 let container = serde_json::from_slice(&r.body)?;
 
-                return Ok(container);
+                Ok(container)
             },
             Err(e) => Err(e),
         }
@@ -191,7 +281,7 @@ impl ContainersServiceTrait for ContainersService {
 
                 let container: InspectedContainer = serde_json::from_slice(&r.body)?;
 
-                return Ok(container);
+                Ok(container)
             },
             Err(e) => Err(e),
         }
@@ -217,7 +307,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
                 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -228,15 +318,16 @@ impl ContainersServiceTrait for ContainersService {
     /// # Arguments
     ///
     /// * id - A string value indicating the id of the container.
-    /// * timeout - An integer value indicating the timeout for the container to stop.
+    /// * signal - The signal sent to stop the container, the stop signal of the container (`SIGTERM` by default) when `None`.
+    /// * timeout - The number of seconds to wait before killing the container, the stop timeout of the container when `None`.
     ///
     /// # Returns
     ///
     /// Returns a Result containing the details of the stopped container in bytes on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     ///
     /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#tag/Container/operation/ContainerStop]
-    fn stop_container(&mut self, id: &str, timeout: i32) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}{}{}?t={}", STOP_CONTAINER_START, id, STOP_CONTAINER_END, timeout);
+    fn stop_container(&mut self, id: &str, signal: Option<Signal>, timeout: Option<i32>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}{}", STOP_CONTAINER_START, id, STOP_CONTAINER_END, signal_query(signal, timeout));
         let response = request(&self.client, self.url.clone(), url, STOP_CONTAINER_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -244,7 +335,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -255,14 +346,16 @@ impl ContainersServiceTrait for ContainersService {
     /// # Arguments
     ///
     /// * id - A string value indicating the id of the container.
+    /// * signal - The signal sent to stop the container, the stop signal of the container (`SIGTERM` by default) when `None`.
+    /// * timeout - The number of seconds to wait before killing the container, the stop timeout of the container when `None`.
     ///
     /// # Returns
     ///
     /// Returns a Result containing the details of the restarted container in bytes on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     ///
     /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#tag/Container/operation/ContainerRestart] for more information.
-    fn restart_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}{}{}", RESTART_CONTAINER_START, id, RESTART_CONTAINER_END);
+    fn restart_container(&mut self, id: &str, signal: Option<Signal>, timeout: Option<i32>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}{}", RESTART_CONTAINER_START, id, RESTART_CONTAINER_END, signal_query(signal, timeout));
         let response = request(&self.client, self.url.clone(), url, RESTART_CONTAINER_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -270,25 +363,26 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
     }
 
-    /// Kills a container.
+    /// Kills a container, or sends it another signal.
     ///
     /// # Arguments
     ///
     /// * id - A string value indicating the id of the container.
+    /// * signal - The signal to send to the container, `SIGKILL` when `None`.
     ///
     /// # Returns
     ///
     /// Returns a Result containing the details of the killed container in bytes on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     ///
     /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#tag/Container/operation/ContainerKill] for more information.
-    fn kill_container(&mut self, id: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}{}{}", KILL_CONTAINER_START, id, KILL_CONTAINER_END);
+    fn kill_container(&mut self, id: &str, signal: Option<Signal>) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}{}", KILL_CONTAINER_START, id, KILL_CONTAINER_END, signal_query(signal, None));
         let response = request(&self.client, self.url.clone(), url, KILL_CONTAINER_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -296,7 +390,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -325,7 +419,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => {
                 Err(e)
@@ -341,11 +435,11 @@ impl ContainersServiceTrait for ContainersService {
     ///
     /// # Returns
     ///
-    /// Returns a Result containing the logs of the container as a string on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// Returns a Result containing the stdout and stderr of the container, interleaved as they were written, on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     ///
     /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerLogs] for more information.
     fn get_container_logs(&mut self, id: &str) -> Result<GetContainerLogsReturn, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}{}{}", GET_CONTAINER_LOGS_START, id, GET_CONTAINER_LOGS_END);
+        let url = format!("{}{}{}?stdout=true&stderr=true", GET_CONTAINER_LOGS_START, id, GET_CONTAINER_LOGS_END);
         let response = request(&self.client, self.url.clone(), url, GET_CONTAINER_LOGS_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -353,27 +447,223 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                let logs = String::from_utf8(r.body.to_vec())?;
+                let logs: Vec<u8> = frames(&r.body).into_iter().flat_map(|frame| frame.payload).collect();
+                let logs = String::from_utf8(logs)?;
+
+                Ok(logs)
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets the changes made to the filesystem of a container, relative to its image.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the paths that were modified, added or deleted in the writable layer of the container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerChanges] for more information.
+    fn get_container_changes(&mut self, id: &str) -> Result<GetContainerChangesReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", GET_CHANGES_CONTAINER_START, id, GET_CHANGES_CONTAINER_END);
+        let response = request(&self.client, self.url.clone(), url, GET_CHANGES_CONTAINER_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                // The Engine answers `null` instead of an empty list when nothing changed
+                let changes: Option<GetContainerChangesReturn> = serde_json::from_slice(&r.body)?;
+
+                Ok(changes.unwrap_or_default())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Exports the filesystem of a container as a tarball.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * writer - Where the tarball is written to, as it is received from the Engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an EmptyOk struct once the whole tarball has been written, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerExport] for more information.
+    fn export_container(&mut self, id: &str, writer: &mut dyn std::io::Write) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", EXPORT_CONTAINER_START, id, EXPORT_CONTAINER_END);
+        let response = request_streamed(&self.client, self.url.clone(), url, EXPORT_CONTAINER_METHOD, 200, writer, &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
 
-                return Ok(logs)
+                Ok(())
             },
             Err(e) => Err(e),
         }
     }
 
+    /// Downloads a path of a container filesystem as a tar archive.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * path - The path inside the container to download.
+    /// * writer - Where the archive is written to, as it is received from the Engine.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the PathStat of the downloaded path on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerArchive] for more information.
+    fn get_container_archive(&mut self, id: &str, path: &str, writer: &mut dyn std::io::Write) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}?path={}", ARCHIVE_CONTAINER_START, id, ARCHIVE_CONTAINER_END, escape(path));
+        let response = request_streamed(&self.client, self.url.clone(), url, GET_ARCHIVE_CONTAINER_METHOD, 200, writer, &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                match r.headers.get(PATH_STAT_HEADER) {
+                    Some(stat) => PathStat::from_header(stat.as_bytes()),
+                    None => Ok(PathStat::default()),
+                }
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets information about a path of a container filesystem, without downloading it.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * path - The path inside the container.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the PathStat decoded from the `X-Docker-Container-Path-Stat` header on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// The error is of kind `NotFound` when the container or the path does not exist.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerArchiveInfo] for more information.
+    fn stat_container_path(&mut self, id: &str, path: &str) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}?path={}", ARCHIVE_CONTAINER_START, id, ARCHIVE_CONTAINER_END, escape(path));
+        let response = request(&self.client, self.url.clone(), url, STAT_ARCHIVE_CONTAINER_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                // A HEAD response has no body to read the error message from
+                match r.status {
+                    200 => {},
+                    404 => return Err(not_found(format!("no such container or path: '{}'", path))),
+                    status => return Err(Box::new(std::io::Error::other(format!("Server error: status {} for path '{}'", status, path)))),
+                }
+
+                match r.headers.get(PATH_STAT_HEADER) {
+                    Some(stat) => PathStat::from_header(stat.as_bytes()),
+                    None => Ok(PathStat::default()),
+                }
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Uploads a tar archive to be extracted into a directory of a container filesystem.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * path - The directory inside the container to extract the archive into, it must exist.
+    /// * reader - Where the archive is read from, it is sent to the Engine as it is read.
+    /// * no_overwrite_dir_non_dir - A boolean value indicating whether to fail if a directory would replace a non-directory, or the other way around.
+    /// * copy_uid_gid - A boolean value indicating whether to keep the UID/GID of the archive entries.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an EmptyOk struct on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/PutContainerArchive] for more information.
+    fn put_container_archive(&mut self, id: &str, path: &str, reader: &mut dyn std::io::Read, no_overwrite_dir_non_dir: bool, copy_uid_gid: bool) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}?path={}&noOverwriteDirNonDir={}&copyUIDGID={}", ARCHIVE_CONTAINER_START, id, ARCHIVE_CONTAINER_END, escape(path), no_overwrite_dir_non_dir, copy_uid_gid);
+        let response = request_upload(&self.client, self.url.clone(), url, PUT_ARCHIVE_CONTAINER_METHOD, "application/x-tar", reader, &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Copies a host file or directory into a directory of a container filesystem.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * host_path - The file or directory to copy, it keeps its name inside the container.
+    /// * container_dir - The directory inside the container to copy into, it must exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an EmptyOk struct on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    fn copy_to_container(&mut self, id: &str, host_path: &Path, container_dir: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let archive = archive_host_path(host_path)?;
+
+        self.put_container_archive(id, container_dir, &mut archive.as_slice(), true, false)
+    }
+
+    /// Copies a file or directory of a container filesystem into a host directory.
+    ///
+    /// The archive is kept in memory while it is downloaded, use `get_container_archive` and `extract_archive` to stream large paths through a file.
+    /// Entries that would be extracted outside of `host_dir` are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * container_path - The file or directory inside the container to copy.
+    /// * host_dir - The host directory to copy into, it is created if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the PathStat of the copied path on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    fn copy_from_container(&mut self, id: &str, container_path: &str, host_dir: &Path) -> Result<ArchiveContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let mut archive: Vec<u8> = vec![];
+        let stat = self.get_container_archive(id, container_path, &mut archive)?;
+        extract_archive(archive.as_slice(), host_dir)?;
+
+        Ok(stat)
+    }
+
     /// Lists the processes running inside a container.
     ///
     /// # Arguments
     ///
     /// * id - A string value indicating the id of the container.
+    /// * ps_args - The arguments to pass to `ps`, such as `-eo pid,ppid,rss,etime,args`, the Engine uses `-ef` when None.
     ///
     /// # Returns
     ///
     /// Returns a Result containing a list of processes running inside the container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     ///
     /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerListProcesses] for more information.
-    fn list_processes(&mut self, id: &str) -> Result<ListProcessesReturn, Box<dyn std::error::Error + Send + Sync>> {
-        let url = format!("{}{}{}", LIST_PROCESSES_START, id, LIST_PROCESSES_END);
+    fn list_processes(&mut self, id: &str, ps_args: Option<&str>) -> Result<ListProcessesReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let mut url = format!("{}{}{}", LIST_PROCESSES_START, id, LIST_PROCESSES_END);
+        if let Some(ps_args) = ps_args {
+            url.push_str(&format!("?ps_args={}", escape(ps_args)));
+        }
+
         let response = request(&self.client, self.url.clone(), url, LIST_PROCESSES_METHOD, "".to_string(), &self.runtime);
         match response {
             Ok(r) => {
@@ -383,7 +673,7 @@ impl ContainersServiceTrait for ContainersService {
 
                 let processes = serde_json::from_slice(&r.body)?;
 
-                return Ok(processes)
+                Ok(processes)
             },
             Err(e) => Err(e),
         }
@@ -404,7 +694,7 @@ impl ContainersServiceTrait for ContainersService {
     /// # Example
     ///
     /// /// let mut client = Docker::connect("http://127.0.0.1:2375").unwrap(); /// let stats = client.get_stats_container("container_id", true, false).unwrap(); ///
-
+    ///
     /// The struct returned by this function contains the following fields:
     ///
     /// * read - A string representing the time the stats were read
@@ -427,7 +717,7 @@ impl ContainersServiceTrait for ContainersService {
 
                 let stats = serde_json::from_slice(&r.body)?;
 
-                return Ok(stats)
+                Ok(stats)
             },
             Err(e) => Err(e),
         }
@@ -455,7 +745,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -481,7 +771,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -507,7 +797,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -522,10 +812,10 @@ impl ContainersServiceTrait for ContainersService {
     /// 
     /// # Returns
     /// 
-    /// Returns a Result containing an EmptyOk struct on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// Returns a Result containing a WaitResponse struct with the exit code of the container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     /// 
     /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerWait] for more information.
-    fn wait_container(&mut self, id: &str, condition: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+    fn wait_container(&mut self, id: &str, condition: WaitCondition) -> Result<WaitContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
         let url = format!("{}{}{}?condition={}", WAIT_CONTAINER_START, id, WAIT_CONTAINER_END, condition);
         let response = request(&self.client, self.url.clone(), url, WAIT_CONTAINER_METHOD, "".to_string(), &self.runtime);
         match response {
@@ -534,7 +824,40 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                let status = serde_json::from_slice(&r.body)?;
+
+                Ok(status)
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Block until a container stops or the timeout elapses, then returns the exit code.
+    /// 
+    /// # Arguments
+    /// 
+    /// * id - The ID of the container
+    /// * condition - The condition to wait for
+    /// * timeout - The maximum time to wait for the condition
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a Result containing a WaitResponse struct with the exit code of the container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// If the timeout elapses first the error is a `std::io::Error` of kind `TimedOut`; the container is left as it is.
+    /// 
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerWait] for more information.
+    fn wait_container_timeout(&mut self, id: &str, condition: WaitCondition, timeout: Duration) -> Result<WaitContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}?condition={}", WAIT_CONTAINER_START, id, WAIT_CONTAINER_END, condition);
+        let response = request_timeout(&self.client, self.url.clone(), url, WAIT_CONTAINER_METHOD, "".to_string(), Some(timeout), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                let status = serde_json::from_slice(&r.body)?;
+
+                Ok(status)
             },
             Err(e) => Err(e),
         }
@@ -553,6 +876,8 @@ impl ContainersServiceTrait for ContainersService {
     /// 
     /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerUpdate] for more information.
     fn update_container(&mut self, id: &str, more: &UpdateContainerWith) -> Result<WarningsResponse, Box<dyn std::error::Error + Send + Sync>> {
+        validate_devices(more.devices.as_deref().unwrap_or_default(), more.device_cgroup_rules.as_deref().unwrap_or_default())?;
+
         let url = format!("{}{}{}", UPDATE_CONTAINER_START, id, UPDATE_CONTAINER_END);
         let response = request(&self.client, self.url.clone(), url, UPDATE_CONTAINER_METHOD, serde_json::to_string(&more)?, &self.runtime);
         match response {
@@ -563,7 +888,7 @@ impl ContainersServiceTrait for ContainersService {
 
                 let warnings = serde_json::from_slice(&r.body)?;
 
-                return Ok(warnings)
+                Ok(warnings)
             },
             Err(e) => Err(e),
         }
@@ -590,7 +915,7 @@ impl ContainersServiceTrait for ContainersService {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
@@ -600,20 +925,318 @@ impl ContainersServiceTrait for ContainersService {
     /// 
     /// # Returns
     /// 
-    /// Returns a Result containing an EmptyOk struct on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// Returns a Result containing a PruneReport struct with the deleted containers and the reclaimed space on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
     /// 
     /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerPrune] for more information.
-    fn delete_stopped_containers(self) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
-        let response = request(&self.client, self.url.clone(), DELETE_STOPPED_CONTAINERS.to_string(), DELETE_STOPPED_CONTAINERS_METHOD, "".to_string(), &self.runtime);
+    fn delete_stopped_containers(&mut self) -> Result<DeleteStoppedContainersReturn, Box<dyn std::error::Error + Send + Sync>> {
+        self.prune_containers(&PruneFilters::default())
+    }
+
+    /// This function delete stopped containers matching the filters.
+    /// 
+    /// # Arguments
+    /// 
+    /// * filters - The PruneFilters struct selecting the containers to delete by creation time and labels
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a Result containing a PruneReport struct with the deleted containers and the reclaimed space on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// 
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerPrune] for more information.
+    fn prune_containers(&mut self, filters: &PruneFilters) -> Result<DeleteStoppedContainersReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}?filters={}", DELETE_STOPPED_CONTAINERS, escape(&filters.to_json()?));
+        let response = request(&self.client, self.url.clone(), url, DELETE_STOPPED_CONTAINERS_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                let report = serde_json::from_slice(&r.body)?;
+
+                Ok(report)
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Pulls an image from its registry.
+    ///
+    /// # Arguments
+    ///
+    /// * image - A string value indicating the image, the `latest` tag is pulled when it has neither a tag nor a digest.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an empty value once the image is pulled on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ImageCreate] for more information.
+    fn pull_image(&mut self, image: &str) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}?fromImage={}", PULL_IMAGE, escape(&pull_reference(image)));
+        let response = request(&self.client, self.url.clone(), url, PULL_IMAGE_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                // The Engine answers 200 as soon as the pull starts, a failure is reported as the last progress message
+                for line in r.body.split(|byte| *byte == b'\n') {
+                    if let Ok(progress) = serde_json::from_slice::<serde_json::Value>(line) {
+                        if let Some(error) = progress.get("error").and_then(|error| error.as_str()) {
+                            return Err(Box::new(std::io::Error::other(format!("Server error: {}", error))));
+                        }
+                    }
+                }
+
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Runs a container to completion and captures its output, like `docker run` without `--detach`.
+    ///
+    /// # Arguments
+    ///
+    /// * config - The configuration of the container, the image is required.
+    /// * options - The name and platform of the container, when to pull the image, and whether to remove the container.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the exit code and the output of the container on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// A container that exits with a non zero code is not an error, check `exit_code`.
+    fn run_container(&mut self, config: &CreateContainerFrom, options: &RunOptions) -> Result<RunContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let mut stdout = vec![];
+        let mut stderr = vec![];
+        let mut output = self.run_container_streamed(config, options, &mut stdout, &mut stderr)?;
+        output.stdout = String::from_utf8_lossy(&stdout).to_string();
+        output.stderr = String::from_utf8_lossy(&stderr).to_string();
+
+        Ok(output)
+    }
+
+    /// Runs a container to completion and writes its output as it is produced, like `docker run` without `--detach`.
+    ///
+    /// The image is pulled according to `options.pull`, then the container is created, started and followed until it exits.
+    /// When `options.remove` is set the container is removed once it exits, and also when starting or following it fails.
+    ///
+    /// # Arguments
+    ///
+    /// * config - The configuration of the container, the image is required.
+    /// * options - The name and platform of the container, when to pull the image, and whether to remove the container.
+    /// * stdout - The writer receiving the stdout of the container, and all its output when it runs with a TTY.
+    /// * stderr - The writer receiving the stderr of the container.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the exit code of the container on success, with empty `stdout` and `stderr`, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    fn run_container_streamed(&mut self, config: &CreateContainerFrom, options: &RunOptions, stdout: &mut dyn std::io::Write, stderr: &mut dyn std::io::Write) -> Result<RunContainerReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let image = match &config.image {
+            Some(image) if !image.is_empty() => image.clone(),
+            _ => return Err(invalid("an image is required to run a container".to_string())),
+        };
+
+        match options.pull {
+            PullPolicy::Always => self.pull_image(&image)?,
+            PullPolicy::Missing => {
+                if !image_exists(self, &image)? {
+                    self.pull_image(&image)?;
+                }
+            },
+            PullPolicy::Never => {},
+        }
+
+        let created = self.create_container(&options.name, &options.platform, config)?;
+        let mut demultiplexer = Demultiplexer::new(stdout, stderr, config.tty.unwrap_or(false));
+        let exit_code = follow_to_exit(self, &created.id, &mut demultiplexer);
+
+        if options.remove {
+            let removed = self.remove_container(&created.id, false, true, false);
+            // Report why the run failed rather than a failure to clean up after it
+            if exit_code.is_ok() {
+                removed?;
+            }
+        }
+
+        Ok(RunOutput { id: created.id, exit_code: exit_code?, ..Default::default() })
+    }
+
+    /// Stops the running containers matching the filters, several at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * filters - The filters selecting the containers to stop.
+    /// * timeout - The number of seconds to wait for each container to stop before killing it, the Engine default when None.
+    /// * parallelism - The maximum number of containers being stopped at the same time, at least 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the outcome for each container on success, or an error of type Box<dyn std::error::Error + Send + Sync> if the containers cannot be listed,
+    /// or of kind `InvalidInput` if the filters are empty and not `ContainerFilters::all()`.
+    /// A container that fails to stop does not prevent the others from being stopped, check `BulkReport::failed`.
+    fn stop_all(&mut self, filters: &ContainerFilters, timeout: Option<i32>, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>> {
+        let query = signal_query(None, timeout);
+        for_each_container(self, false, filters, parallelism, |id| {
+            (format!("{}{}{}{}", STOP_CONTAINER_START, id, STOP_CONTAINER_END, query), STOP_CONTAINER_METHOD)
+        })
+    }
+
+    /// Restarts the running containers matching the filters, several at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * filters - The filters selecting the containers to restart.
+    /// * timeout - The number of seconds to wait for each container to stop before killing it, the Engine default when None.
+    /// * parallelism - The maximum number of containers being restarted at the same time, at least 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the outcome for each container on success, or an error of type Box<dyn std::error::Error + Send + Sync> if the containers cannot be listed,
+    /// or of kind `InvalidInput` if the filters are empty and not `ContainerFilters::all()`.
+    /// A container that fails to restart does not prevent the others from being restarted, check `BulkReport::failed`.
+    fn restart_all(&mut self, filters: &ContainerFilters, timeout: Option<i32>, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>> {
+        let query = signal_query(None, timeout);
+        for_each_container(self, false, filters, parallelism, |id| {
+            (format!("{}{}{}{}", RESTART_CONTAINER_START, id, RESTART_CONTAINER_END, query), RESTART_CONTAINER_METHOD)
+        })
+    }
+
+    /// Removes the containers matching the filters, stopped ones included, several at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * filters - The filters selecting the containers to remove.
+    /// * remove_associated_volumes - A boolean value indicating whether to remove the anonymous volumes of the containers.
+    /// * force - A boolean value indicating whether to kill and remove running containers, which fail to be removed otherwise.
+    /// * parallelism - The maximum number of containers being removed at the same time, at least 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the outcome for each container on success, or an error of type Box<dyn std::error::Error + Send + Sync> if the containers cannot be listed,
+    /// or of kind `InvalidInput` if the filters are empty and not `ContainerFilters::all()`.
+    /// A container that fails to be removed does not prevent the others from being removed, check `BulkReport::failed`.
+    fn remove_all(&mut self, filters: &ContainerFilters, remove_associated_volumes: bool, force: bool, parallelism: usize) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>> {
+        for_each_container(self, true, filters, parallelism, |id| {
+            (format!("{}{}?v={}&force={}", REMOVE_CONTAINER, id, remove_associated_volumes, force), REMOVE_CONTAINER_METHOD)
+        })
+    }
+}
+
+impl ContainersService {
+    /// Exports the filesystem of a container as a tarball, same as `export_container` for writers implementing `tokio::io::AsyncWrite`.
+    ///
+    /// The request runs on the runtime of the caller, so it can be awaited from async code, where the blocking methods would panic.
+    ///
+    /// See the Docker API reference[https://docs.docker.com/engine/api/v1.41/#operation/ContainerExport] for more information.
+    pub async fn export_container_async<W: tokio::io::AsyncWrite + Unpin + ?Sized>(&self, id: &str, writer: &mut W) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", EXPORT_CONTAINER_START, id, EXPORT_CONTAINER_END);
+        let response = request_streamed_async(&self.client, self.url.clone(), url, EXPORT_CONTAINER_METHOD, 200, writer).await;
         match response {
             Ok(r) => {
                 if r.status != 200 {
                     return Err(err(&r.body)?);
                 }
 
-                return Ok(())
+                Ok(())
             },
             Err(e) => Err(e),
         }
     }
 }
+
+/// Returns the query string of the stop, restart and kill requests, with its leading `?`, or an empty string when there is no parameter.
+fn signal_query(signal: Option<Signal>, timeout: Option<i32>) -> String {
+    let mut query = vec![];
+    if let Some(signal) = signal {
+        query.push(format!("signal={}", signal));
+    }
+
+    if let Some(timeout) = timeout {
+        query.push(format!("t={}", timeout));
+    }
+
+    if query.is_empty() {
+        return String::new();
+    }
+
+    format!("?{}", query.join("&"))
+}
+
+fn image_exists(service: &mut ContainersService, image: &str) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let url = format!("{}{}{}", INSPECT_IMAGE_START, escape(image), INSPECT_IMAGE_END);
+    let response = request(&service.client, service.url.clone(), url, INSPECT_IMAGE_METHOD, "".to_string(), &service.runtime)?;
+    match response.status {
+        200 => Ok(true),
+        404 => Ok(false),
+        _ => Err(err(&response.body)?),
+    }
+}
+
+fn follow_to_exit<W: std::io::Write + ?Sized>(service: &mut ContainersService, id: &str, writer: &mut W) -> Result<i64, Box<dyn std::error::Error + Send + Sync>> {
+    service.start_container(id)?;
+
+    // Following the logs returns once the container exits, the wait then only collects the exit code
+    let url = format!("{}{}{}?follow=true&stdout=true&stderr=true", GET_CONTAINER_LOGS_START, id, GET_CONTAINER_LOGS_END);
+    let response = request_streamed(&service.client, service.url.clone(), url, GET_CONTAINER_LOGS_METHOD, 200, writer, &service.runtime)?;
+    if response.status != 200 {
+        return Err(err(&response.body)?);
+    }
+
+    let exited = service.wait_container(id, WaitCondition::NotRunning)?;
+    if let Some(error) = exited.error {
+        if !error.message.is_empty() {
+            return Err(Box::new(std::io::Error::other(format!("Server error: {}", error.message))));
+        }
+    }
+
+    Ok(exited.status_code)
+}
+
+/// Sends the request built by `action` for each container matching the filters, with at most `parallelism` requests in flight.
+///
+/// Empty filters are rejected unless they opt in with `match_all`, so that a forgotten filter does not act on every container.
+fn for_each_container<F: Fn(&str) -> (String, Method)>(service: &mut ContainersService, all: bool, filters: &ContainerFilters, parallelism: usize, action: F) -> Result<BulkReport, Box<dyn std::error::Error + Send + Sync>> {
+    if filters.is_empty() && !filters.match_all {
+        return Err(invalid("empty filters select every container, use ContainerFilters::all() to act on all of them".to_string()));
+    }
+
+    let containers = service.list_containers(all, 0, false, filters)?;
+    let semaphore = Arc::new(Semaphore::new(parallelism.max(1)));
+
+    let results = service.runtime.block_on(async {
+        let mut tasks = vec![];
+        for container in containers {
+            let (url, method) = action(&container.id);
+            let client = service.client.clone();
+            let socket = service.url.clone();
+            let semaphore = semaphore.clone();
+            let name = container.names.first().map(|name| name.trim_start_matches('/').to_string()).unwrap_or_default();
+            let task = tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await?;
+                let response = request_async(&client, socket, url, method, "".to_string()).await?;
+                // 304 means the container was already in the requested state
+                if response.status != 204 && response.status != 304 {
+                    return Err(err(&response.body)?);
+                }
+
+                Ok::<EmptyOk, Box<dyn std::error::Error + Send + Sync>>(())
+            });
+            tasks.push((container.id, name, task));
+        }
+
+        let mut results = vec![];
+        for (id, name, task) in tasks {
+            // A task that panicked fails for its container only
+            let result = match task.await {
+                Ok(result) => result,
+                Err(e) => Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>),
+            };
+            results.push(BulkResult { id, name, result });
+        }
+
+        results
+    });
+
+    Ok(BulkReport { results })
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a `null` sent by the Engine as the default value of the field, e.g. an empty list or map.
+pub fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    let value: Option<T> = Option::deserialize(deserializer)?;
+    Ok(value.unwrap_or_default())
+}
@@ -2,10 +2,20 @@ use crate::api::ErrorMessage;
 
 pub fn err(bytes: &[u8]) -> Result<Box<dyn std::error::Error + Send + Sync>, Box<dyn std::error::Error + Send + Sync>>  {
     let err_string = String::from_utf8(bytes.to_vec())?;
-    if err_string.len() == 0 {
-        return Err(Box::new(std::io::Error::new(std::io::ErrorKind::Other, "Server error: Unknown")).into());
+    if err_string.is_empty() {
+        return Err(Box::new(std::io::Error::other("Server error: Unknown")).into());
     }
 
     let err: ErrorMessage = serde_json::from_slice(bytes)?;
-    return Err(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Server error: {}", err.message))).into());
-}
\ No newline at end of file
+    Err(Box::new(std::io::Error::other(format!("Server error: {}", err.message))).into())
+}
+
+/// Builds the error returned when a value provided by the caller is not valid, before anything is sent to the Engine.
+pub fn invalid(message: String) -> Box<dyn std::error::Error + Send + Sync> {
+    Box::new(std::io::Error::new(std::io::ErrorKind::InvalidInput, message))
+}
+
+/// Builds the error returned when nothing matches a reference provided by the caller, such as a container name.
+pub fn not_found(message: String) -> Box<dyn std::error::Error + Send + Sync> {
+    Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, message))
+}
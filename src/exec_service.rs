@@ -0,0 +1,227 @@
+use crate::{api::{
+    EmptyOk,
+
+    CREATE_EXEC_START,
+    CREATE_EXEC_END,
+    CREATE_EXEC_METHOD,
+    CreateExecReturn,
+
+    START_EXEC_START,
+    START_EXEC_END,
+    START_EXEC_METHOD,
+    StartExecReturn,
+
+    RESIZE_EXEC_START,
+    RESIZE_EXEC_END,
+    RESIZE_EXEC_METHOD,
+
+    INSPECT_EXEC_START,
+    INSPECT_EXEC_END,
+    INSPECT_EXEC_METHOD,
+    InspectExecReturn,
+}, exec_structs::{ExecConfig, ExecStartConfig, ExecOutput}, request::{request, request_attached}, error::err, stream::demultiplex};
+
+use std::time::{Duration, Instant};
+
+use hyper::{Client as HyperClient};
+use hyperlocal::{UnixConnector};
+
+pub struct ExecService {
+    pub url: String,
+    pub client: HyperClient<UnixConnector>,
+    pub runtime: tokio::runtime::Runtime,
+}
+
+pub trait ExecServiceTrait {
+    fn create_exec(&mut self, container_id: &str, config: &ExecConfig) -> Result<CreateExecReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn start_exec(&mut self, id: &str, config: &ExecStartConfig) -> Result<StartExecReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn resize_exec(&mut self, id: &str, height: i32, width: i32) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>>;
+    fn inspect_exec(&mut self, id: &str) -> Result<InspectExecReturn, Box<dyn std::error::Error + Send + Sync>>;
+    fn run_in_container(&mut self, id: &str, cmd: Vec<String>, stdin: Option<Vec<u8>>, timeout: Option<Duration>) -> Result<ExecOutput, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+const EXEC_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+impl ExecServiceTrait for ExecService {
+    /// Creates an exec instance inside a running container.
+    ///
+    /// # Arguments
+    ///
+    /// * container_id - A string value indicating the id of the container.
+    /// * config - A struct containing the command, environment and attach options of the exec instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the id of the created exec instance on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerExec] for more information.
+    fn create_exec(&mut self, container_id: &str, config: &ExecConfig) -> Result<CreateExecReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", CREATE_EXEC_START, container_id, CREATE_EXEC_END);
+        let body = serde_json::to_string(&config)?;
+        let response = request(&self.client, self.url.clone(), url, CREATE_EXEC_METHOD, body, &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 201 {
+                    return Err(err(&r.body)?);
+                }
+
+                let exec = serde_json::from_slice(&r.body)?;
+
+                Ok(exec)
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Starts an exec instance.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the exec instance.
+    /// * config - A struct indicating whether to detach from the process and whether to allocate a TTY.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the raw output of the process on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// When detached the output is empty; when attached without a TTY the output is multiplexed as described in the
+    /// Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerAttach].
+    ///
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ExecStart] for more information.
+    fn start_exec(&mut self, id: &str, config: &ExecStartConfig) -> Result<StartExecReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", START_EXEC_START, id, START_EXEC_END);
+        let body = serde_json::to_string(&config)?;
+        let response = request(&self.client, self.url.clone(), url, START_EXEC_METHOD, body, &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                Ok(r.body.to_vec())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Resizes the TTY session used by an exec instance.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the exec instance.
+    /// * height - The height of the tty
+    /// * width - The width of the tty
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing an EmptyOk struct on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ExecResize] for more information.
+    fn resize_exec(&mut self, id: &str, height: i32, width: i32) -> Result<EmptyOk, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}?h={}&w={}", RESIZE_EXEC_START, id, RESIZE_EXEC_END, height, width);
+        let response = request(&self.client, self.url.clone(), url, RESIZE_EXEC_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 && r.status != 201 {
+                    return Err(err(&r.body)?);
+                }
+
+                Ok(())
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Inspects an exec instance.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the exec instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the details of the exec instance, including its exit code once finished, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    ///
+    /// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ExecInspect] for more information.
+    fn inspect_exec(&mut self, id: &str) -> Result<InspectExecReturn, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{}{}{}", INSPECT_EXEC_START, id, INSPECT_EXEC_END);
+        let response = request(&self.client, self.url.clone(), url, INSPECT_EXEC_METHOD, "".to_string(), &self.runtime);
+        match response {
+            Ok(r) => {
+                if r.status != 200 {
+                    return Err(err(&r.body)?);
+                }
+
+                let exec = serde_json::from_slice(&r.body)?;
+
+                Ok(exec)
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Runs a command inside a running container and waits for it to finish.
+    ///
+    /// The exec instance is created and started attached, its output is demultiplexed into stdout and stderr,
+    /// and the exit code is read by inspecting the exec instance once the process has finished.
+    ///
+    /// # Arguments
+    ///
+    /// * id - A string value indicating the id of the container.
+    /// * cmd - The command to run and its arguments.
+    /// * stdin - Optional bytes written to the standard input of the process, which is closed afterwards.
+    /// * timeout - Optional maximum time to wait for the process to finish.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Result containing the exit code and the output of the process on success, or an error of type Box<dyn std::error::Error + Send + Sync> on failure.
+    /// If the timeout elapses an error of kind `std::io::ErrorKind::TimedOut` is returned, the process is left running.
+    /// It is an error as well when the Engine reports the process stopped without an exit code.
+    fn run_in_container(&mut self, id: &str, cmd: Vec<String>, stdin: Option<Vec<u8>>, timeout: Option<Duration>) -> Result<ExecOutput, Box<dyn std::error::Error + Send + Sync>> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        let config = ExecConfig {
+            attach_stdin: Some(stdin.is_some()),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            cmd: Some(cmd),
+            ..Default::default()
+        };
+
+        let exec = self.create_exec(id, &config)?;
+
+        let url = format!("{}{}{}", START_EXEC_START, exec.id, START_EXEC_END);
+        let body = serde_json::to_string(&ExecStartConfig::default())?;
+        let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+        let response = request_attached(&self.client, self.url.clone(), url, START_EXEC_METHOD, body, stdin, remaining, &self.runtime)?;
+        if response.status != 101 && response.status != 200 {
+            return Err(err(&response.body)?);
+        }
+
+        let (stdout, stderr) = demultiplex(&response.body);
+
+        loop {
+            let inspected = self.inspect_exec(&exec.id)?;
+            if !inspected.running {
+                // A finished exec instance without an exit code did not run to completion, it must not be reported as a success
+                let exit_code = match inspected.exit_code {
+                    Some(exit_code) => exit_code,
+                    None => return Err(Box::new(std::io::Error::other(format!("exec instance '{}' stopped without an exit code", exec.id)))),
+                };
+
+                return Ok(ExecOutput {
+                    exit_code,
+                    stdout: String::from_utf8_lossy(&stdout).to_string(),
+                    stderr: String::from_utf8_lossy(&stderr).to_string(),
+                });
+            }
+
+            if let Some(deadline) = deadline {
+                if Instant::now() >= deadline {
+                    return Err(Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "Timed out waiting for the exec instance to finish")));
+                }
+            }
+
+            std::thread::sleep(EXEC_POLL_INTERVAL);
+        }
+    }
+}
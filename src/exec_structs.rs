@@ -0,0 +1,96 @@
+use serde::{Serialize, Deserialize};
+
+/// ExecConfig is the struct that is used to create an exec instance
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExecConfig {
+    #[serde(rename = "AttachStdin", skip_serializing_if = "Option::is_none", default)]
+    pub attach_stdin: Option<bool>,
+    #[serde(rename = "AttachStdout", skip_serializing_if = "Option::is_none", default)]
+    pub attach_stdout: Option<bool>,
+    #[serde(rename = "AttachStderr", skip_serializing_if = "Option::is_none", default)]
+    pub attach_stderr: Option<bool>,
+    #[serde(rename = "ConsoleSize", skip_serializing_if = "Option::is_none", default)]
+    pub console_size: Option<Vec<u16>>,
+    #[serde(rename = "DetachKeys", skip_serializing_if = "Option::is_none", default)]
+    pub detach_keys: Option<String>,
+    #[serde(rename = "Tty", skip_serializing_if = "Option::is_none", default)]
+    pub tty: Option<bool>,
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none", default)]
+    pub env: Option<Vec<String>>,
+    #[serde(rename = "Cmd", skip_serializing_if = "Option::is_none", default)]
+    pub cmd: Option<Vec<String>>,
+    #[serde(rename = "Privileged", skip_serializing_if = "Option::is_none", default)]
+    pub privileged: Option<bool>,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none", default)]
+    pub user: Option<String>,
+    #[serde(rename = "WorkingDir", skip_serializing_if = "Option::is_none", default)]
+    pub working_dir: Option<String>,
+}
+
+/// ExecStartConfig is the struct that is used to start an exec instance
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExecStartConfig {
+    /// Detach from the command, the Engine returns as soon as the process is started.
+    #[serde(rename = "Detach", default)]
+    pub detach: bool,
+    /// Allocate a pseudo-TTY, stdout and stderr are not multiplexed when enabled.
+    #[serde(rename = "Tty", default)]
+    pub tty: bool,
+    #[serde(rename = "ConsoleSize", skip_serializing_if = "Option::is_none", default)]
+    pub console_size: Option<Vec<u16>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct CreateExecResponseFromAPI {
+    #[serde(rename = "Id", default)]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProcessConfig {
+    #[serde(rename = "privileged", default)]
+    pub privileged: bool,
+    #[serde(rename = "user", default)]
+    pub user: String,
+    #[serde(rename = "tty", default)]
+    pub tty: bool,
+    #[serde(rename = "entrypoint", default)]
+    pub entrypoint: String,
+    #[serde(rename = "arguments", default)]
+    pub arguments: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InspectedExec {
+    #[serde(rename = "CanRemove", default)]
+    pub can_remove: bool,
+    #[serde(rename = "DetachKeys", default)]
+    pub detach_keys: String,
+    #[serde(rename = "ID", default)]
+    pub id: String,
+    #[serde(rename = "Running", default)]
+    pub running: bool,
+    /// The exit code of the process, `None` while the process is still running.
+    #[serde(rename = "ExitCode", default)]
+    pub exit_code: Option<i64>,
+    #[serde(rename = "ProcessConfig", default)]
+    pub process_config: ProcessConfig,
+    #[serde(rename = "OpenStdin", default)]
+    pub open_stdin: bool,
+    #[serde(rename = "OpenStderr", default)]
+    pub open_stderr: bool,
+    #[serde(rename = "OpenStdout", default)]
+    pub open_stdout: bool,
+    #[serde(rename = "ContainerID", default)]
+    pub container_id: String,
+    #[serde(rename = "Pid", default)]
+    pub pid: u64,
+}
+
+/// ExecOutput is the result of running a command to completion inside a container
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecOutput {
+    pub exit_code: i64,
+    pub stdout: String,
+    pub stderr: String,
+}
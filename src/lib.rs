@@ -3,19 +3,43 @@ pub mod client;
 pub mod network;
 pub mod container_network;
 pub mod container_create;
+pub mod container_host_config;
+pub mod container_spec;
+pub mod container_health;
+pub mod container_state;
+pub mod container_ports;
+pub mod container_mounts;
+pub mod container_devices;
 pub mod container_procceses;
 pub mod container_stats;
 pub mod container_inspect;
 pub mod container_update;
+pub mod container_resources;
+pub mod container_changes;
+pub mod container_archive;
+pub mod container_prune;
+pub mod container_wait;
+pub mod container_resolve;
+pub mod container_run;
+pub mod container_ready;
+pub mod container_filters;
+pub mod container_bulk;
 pub mod containers_service;
+pub mod exec_structs;
+pub mod exec_service;
 pub mod container_structs;
 pub mod request;
+pub mod signal;
+pub mod units;
+pub mod stream;
 pub mod error;
+pub mod deserialize;
+pub mod timestamp;
 
 #[cfg(test)]
 mod tests;
 
 use crate::client::ClientTrait;
 pub fn new(url: String) -> client::Client {
-    return client::Client::new(url);
+    client::Client::new(url)
 }
\ No newline at end of file
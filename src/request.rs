@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use hyper::body::Bytes;
-use hyper::{Client as HyperClient, Method, Request, Body, body::HttpBody};
+use hyper::{Client as HyperClient, HeaderMap, Method, Request, Body, body::HttpBody};
 use hyperlocal::{UnixConnector, Uri};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::runtime::Runtime;
 
 pub struct SimpleResponse {
     pub status: u16,
+    pub headers: HeaderMap,
     pub body: Bytes,
 }
 
@@ -12,36 +16,233 @@ impl Default for SimpleResponse {
     fn default() -> Self {
         SimpleResponse {
             status: 0,
+            headers: HeaderMap::new(),
             body: Bytes::new(),
         }
     }
 }
 
 pub fn request(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, body: String, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    request_timeout(client, socket, url, method, body, None, runtime)
+}
+
+/// Same as `request`, but fails with an error of kind `TimedOut` if the whole response is not received before `timeout` elapses.
+pub fn request_timeout(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, body: String, timeout: Option<Duration>, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    runtime.block_on(async {
+        with_timeout(timeout, request_async(client, socket, url, method, body), "Timed out waiting for the response").await
+    })
+}
+
+/// Same as `request`, to be awaited inside the runtime, e.g. to send several requests concurrently.
+pub async fn request_async(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, body: String) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let uri = Uri::new(&socket, &url);
+    let body = Body::from(body);
+
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .body(body)?;
+
+    let mut response = client.request(req).await?;
+
+    let mut chunks = vec![];
+    while let Some(next) = response.data().await {
+        let chunk = next?;
+        chunks.extend_from_slice(&chunk);
+    }
+
+    Ok(SimpleResponse {
+        status: response.status().as_u16(),
+        headers: response.headers().clone(),
+        body: Bytes::from(chunks),
+    })
+}
+
+async fn with_timeout<F>(timeout: Option<Duration>, future: F, message: &str) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>>
+where
+    F: std::future::Future<Output = Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>>>,
+{
+    match timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, future).await {
+            Ok(response) => response,
+            Err(_) => Err(Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, message.to_string())).into()),
+        },
+        None => future.await,
+    }
+}
+
+/// Sends a request asking the Engine to hijack the connection, as done by the attach and exec start endpoints.
+///
+/// When the Engine upgrades the connection `stdin` is written to the process and the write half is closed,
+/// then everything the process outputs is read until the Engine closes the connection.
+/// If `timeout` elapses before that an error of kind `TimedOut` is returned.
+#[allow(clippy::too_many_arguments)]
+pub fn request_attached(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, body: String, stdin: Option<Vec<u8>>, timeout: Option<Duration>, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    runtime.block_on(async {
+        let attached = async {
+            let uri = Uri::new(&socket, &url);
+            let body = Body::from(body);
+
+            let req = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("Content-Type", "application/json")
+                .header("Connection", "Upgrade")
+                .header("Upgrade", "tcp")
+                .body(body)?;
+
+            let mut response = client.request(req).await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+
+            let mut chunks = vec![];
+            if status == 101 {
+                let mut upgraded = hyper::upgrade::on(response).await?;
+                if let Some(input) = stdin {
+                    upgraded.write_all(&input).await?;
+                    upgraded.shutdown().await?;
+                }
+
+                upgraded.read_to_end(&mut chunks).await?;
+            } else {
+                while let Some(next) = response.data().await {
+                    let chunk = next?;
+                    chunks.extend_from_slice(&chunk);
+                }
+            }
+
+            Ok::<SimpleResponse, Box<dyn std::error::Error + Send + Sync>>(SimpleResponse {
+                status,
+                headers,
+                body: Bytes::from(chunks),
+            })
+        };
+
+        with_timeout(timeout, attached, "Timed out waiting for the attached stream").await
+    })
+}
+
+/// Sends a request and copies the response body into `writer` chunk by chunk, without buffering it in memory.
+///
+/// The body is only copied when the response status is `success`; otherwise it is buffered and returned,
+/// so the caller can turn it into an error.
+pub fn request_streamed<W: std::io::Write + ?Sized>(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, success: u16, writer: &mut W, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    request_streamed_timeout(client, socket, url, method, success, writer, None, runtime)
+}
+
+/// Same as `request_streamed`, but fails with an error of kind `TimedOut` if the whole response is not received before `timeout` elapses.
+#[allow(clippy::too_many_arguments)]
+pub fn request_streamed_timeout<W: std::io::Write + ?Sized>(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, success: u16, writer: &mut W, timeout: Option<Duration>, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    runtime.block_on(async {
+        let streamed = async {
+            let mut response = send(client, socket, url, method).await?;
+            let status = response.status().as_u16();
+            if status != success {
+                return buffer(status, response).await;
+            }
+
+            while let Some(next) = response.data().await {
+                let chunk = next?;
+                writer.write_all(&chunk)?;
+            }
+            writer.flush()?;
+
+            Ok(SimpleResponse { status, headers: response.headers().clone(), body: Bytes::new() })
+        };
+
+        with_timeout(timeout, streamed, "Timed out waiting for the streamed response").await
+    })
+}
+
+/// Same as `request_streamed`, for writers implementing `tokio::io::AsyncWrite`, to be awaited inside the runtime of the caller.
+pub async fn request_streamed_async<W: tokio::io::AsyncWrite + Unpin + ?Sized>(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, success: u16, writer: &mut W) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let mut response = send(client, socket, url, method).await?;
+    let status = response.status().as_u16();
+    if status != success {
+        return buffer(status, response).await;
+    }
+
+    while let Some(next) = response.data().await {
+        let chunk = next?;
+        writer.write_all(&chunk).await?;
+    }
+    writer.flush().await?;
+
+    Ok(SimpleResponse { status, headers: response.headers().clone(), body: Bytes::new() })
+}
+
+async fn send(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method) -> Result<hyper::Response<Body>, Box<dyn std::error::Error + Send + Sync>> {
+    let uri = Uri::new(&socket, &url);
+
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(Body::empty())?;
+
+    Ok(client.request(req).await?)
+}
+
+async fn buffer(status: u16, mut response: hyper::Response<Body>) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let headers = response.headers().clone();
+    let mut chunks = vec![];
+    while let Some(next) = response.data().await {
+        let chunk = next?;
+        chunks.extend_from_slice(&chunk);
+    }
+
+    Ok(SimpleResponse { status, headers, body: Bytes::from(chunks) })
+}
+
+/// Sends a request whose body is read from `reader` chunk by chunk, without buffering it in memory.
+pub fn request_upload<R: std::io::Read + ?Sized>(client: &HyperClient<UnixConnector>, socket: String, url: String, method: Method, content_type: &str, reader: &mut R, runtime: &Runtime) -> Result<SimpleResponse, Box<dyn std::error::Error + Send + Sync>> {
     runtime.block_on(async {
         let uri = Uri::new(&socket, &url);
-        let body = Body::from(body);
+        let (mut sender, body) = Body::channel();
 
         let req = Request::builder()
             .method(method)
             .uri(uri)
-            .header("Content-Type", "application/json")
-            .body(body)?
-            .into();
+            .header("Content-Type", content_type)
+            .body(body)?;
 
-        let mut response = client.request(req).await?; 
+        let upload = async move {
+            let mut chunk = vec![0; UPLOAD_CHUNK_SIZE];
+            loop {
+                let read = reader.read(&mut chunk)?;
+                if read == 0 {
+                    return Ok::<(), Box<dyn std::error::Error + Send + Sync>>(());
+                }
 
-        let chunks = vec![];
-        while let Some(next) = response.data().await {
-            let chunk = next?;
-            chunks.extend_from_slice(&chunk);
-        }
-
-        let simple = SimpleResponse {
-            status: response.status().as_u16(),
-            body: chunks,
+                sender.send_data(Bytes::copy_from_slice(&chunk[..read])).await?;
+            }
         };
 
-        Ok(simple);
+        let (response, uploaded) = tokio::join!(client.request(req), upload);
+        let response = response?;
+        let status = response.status().as_u16();
+        let simple = buffer(status, response).await?;
+
+        // The Engine may answer an error before reading the whole body, report that error instead of the broken upload
+        if status < 400 {
+            uploaded?;
+        }
+
+        Ok(simple)
     })
-}
\ No newline at end of file
+}
+
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Escapes a value to be used in the query string of a request.
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,143 @@
+use serde::{Serialize, Deserialize};
+use std::{fmt, str::FromStr};
+
+use crate::error::invalid;
+
+const NAMES: [(&str, i32); 34] = [
+    ("SIGHUP", 1),
+    ("SIGINT", 2),
+    ("SIGQUIT", 3),
+    ("SIGILL", 4),
+    ("SIGTRAP", 5),
+    ("SIGABRT", 6),
+    ("SIGIOT", 6),
+    ("SIGBUS", 7),
+    ("SIGFPE", 8),
+    ("SIGKILL", 9),
+    ("SIGUSR1", 10),
+    ("SIGSEGV", 11),
+    ("SIGUSR2", 12),
+    ("SIGPIPE", 13),
+    ("SIGALRM", 14),
+    ("SIGTERM", 15),
+    ("SIGSTKFLT", 16),
+    ("SIGCHLD", 17),
+    ("SIGCLD", 17),
+    ("SIGCONT", 18),
+    ("SIGSTOP", 19),
+    ("SIGTSTP", 20),
+    ("SIGTTIN", 21),
+    ("SIGTTOU", 22),
+    ("SIGURG", 23),
+    ("SIGXCPU", 24),
+    ("SIGXFSZ", 25),
+    ("SIGVTALRM", 26),
+    ("SIGPROF", 27),
+    ("SIGWINCH", 28),
+    ("SIGIO", 29),
+    ("SIGPOLL", 29),
+    ("SIGPWR", 30),
+    ("SIGSYS", 31),
+];
+
+const SIGRTMIN: i32 = 34;
+const SIGRTMAX: i32 = 64;
+
+/// Signal is a Linux signal sent to the main process of a container
+///
+/// It can be parsed from a name with or without the `SIG` prefix (`SIGHUP`, `hup`), a real-time signal (`SIGRTMIN+3`)
+/// or a number (`1`), and is sent to the Engine by its name.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Signal(i32);
+
+impl Signal {
+    pub const SIGHUP: Signal = Signal(1);
+    pub const SIGINT: Signal = Signal(2);
+    pub const SIGQUIT: Signal = Signal(3);
+    pub const SIGKILL: Signal = Signal(9);
+    pub const SIGUSR1: Signal = Signal(10);
+    pub const SIGUSR2: Signal = Signal(12);
+    pub const SIGTERM: Signal = Signal(15);
+    pub const SIGCONT: Signal = Signal(18);
+    pub const SIGSTOP: Signal = Signal(19);
+    pub const SIGWINCH: Signal = Signal(28);
+
+    pub fn from_number(number: i32) -> Result<Signal, Box<dyn std::error::Error + Send + Sync>> {
+        if NAMES.iter().any(|(_, n)| *n == number) || (SIGRTMIN..=SIGRTMAX).contains(&number) {
+            return Ok(Signal(number));
+        }
+
+        Err(invalid(format!("invalid signal: {}", number)))
+    }
+
+    pub fn number(&self) -> i32 {
+        self.0
+    }
+
+    /// The canonical name of the signal, e.g. `SIGHUP` or `SIGRTMIN+3`.
+    pub fn name(&self) -> String {
+        match NAMES.iter().find(|(_, n)| *n == self.0) {
+            Some((name, _)) => name.to_string(),
+            None if self.0 == SIGRTMIN => "SIGRTMIN".to_string(),
+            None if self.0 == SIGRTMAX => "SIGRTMAX".to_string(),
+            None => format!("SIGRTMIN+{}", self.0 - SIGRTMIN),
+        }
+    }
+}
+
+impl FromStr for Signal {
+    type Err = Box<dyn std::error::Error + Send + Sync>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = value.parse::<i32>() {
+            return Signal::from_number(number);
+        }
+
+        let upper = value.to_uppercase();
+        let name = if upper.starts_with("SIG") { upper } else { format!("SIG{}", upper) };
+
+        if let Some((_, number)) = NAMES.iter().find(|(n, _)| *n == name) {
+            return Ok(Signal(*number));
+        }
+
+        let realtime = match name.as_str() {
+            "SIGRTMIN" => Some(SIGRTMIN),
+            "SIGRTMAX" => Some(SIGRTMAX),
+            _ => {
+                if let Some(offset) = name.strip_prefix("SIGRTMIN+") {
+                    offset.parse::<i32>().ok().map(|offset| SIGRTMIN + offset)
+                } else if let Some(offset) = name.strip_prefix("SIGRTMAX-") {
+                    offset.parse::<i32>().ok().map(|offset| SIGRTMAX - offset)
+                } else {
+                    None
+                }
+            }
+        };
+
+        match realtime {
+            Some(number) if (SIGRTMIN..=SIGRTMAX).contains(&number) => Ok(Signal(number)),
+            _ => Err(invalid(format!("invalid signal: {}", value))),
+        }
+    }
+}
+
+impl TryFrom<String> for Signal {
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Signal> for String {
+    fn from(signal: Signal) -> Self {
+        signal.name()
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
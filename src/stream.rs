@@ -0,0 +1,138 @@
+use std::io::Write;
+
+/// StreamType identifies the stream a frame of a multiplexed attach/logs/exec response belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamType {
+    Stdin,
+    Stdout,
+    Stderr,
+}
+
+impl StreamType {
+    fn from_byte(byte: u8) -> Option<StreamType> {
+        match byte {
+            0 => Some(StreamType::Stdin),
+            1 => Some(StreamType::Stdout),
+            2 => Some(StreamType::Stderr),
+            _ => None,
+        }
+    }
+}
+
+/// A single frame of a multiplexed stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub stream: StreamType,
+    pub payload: Vec<u8>,
+}
+
+const HEADER_LEN: usize = 8;
+
+/// Splits a multiplexed stream into frames.
+///
+/// When a container or exec instance runs without a TTY the Engine prefixes every chunk of output with an
+/// 8 bytes header `[STREAM_TYPE, 0, 0, 0, SIZE1, SIZE2, SIZE3, SIZE4]`, where the size is big endian.
+/// If the bytes do not start with a valid header (e.g. the output of a TTY session) the whole input is returned as a single stdout frame.
+///
+/// See the Docker API reference [https://docs.docker.com/engine/api/v1.41/#operation/ContainerAttach] for more information.
+pub fn frames(bytes: &[u8]) -> Vec<Frame> {
+    let mut frames = vec![];
+    let mut rest = bytes;
+
+    while !rest.is_empty() {
+        let stream = match header(rest) {
+            Some(stream) => stream,
+            None => {
+                frames.push(Frame { stream: StreamType::Stdout, payload: rest.to_vec() });
+                break;
+            }
+        };
+
+        let size = u32::from_be_bytes([rest[4], rest[5], rest[6], rest[7]]) as usize;
+        let end = std::cmp::min(HEADER_LEN + size, rest.len());
+        frames.push(Frame { stream, payload: rest[HEADER_LEN..end].to_vec() });
+        rest = &rest[end..];
+    }
+
+    frames
+}
+
+/// Splits a multiplexed stream into its stdout and stderr contents, in that order.
+pub fn demultiplex(bytes: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut stdout = vec![];
+    let mut stderr = vec![];
+
+    for frame in frames(bytes) {
+        match frame.stream {
+            StreamType::Stderr => stderr.extend_from_slice(&frame.payload),
+            _ => stdout.extend_from_slice(&frame.payload),
+        }
+    }
+
+    (stdout, stderr)
+}
+
+/// Demultiplexer splits a multiplexed stream into two writers as it is received, chunk by chunk
+///
+/// Chunks do not need to hold whole frames, an incomplete frame is kept until the rest of it is written.
+/// When `tty` is true, or the stream does not start with a valid header, everything is written to stdout as is.
+pub struct Demultiplexer<'a, O: Write + ?Sized, E: Write + ?Sized> {
+    stdout: &'a mut O,
+    stderr: &'a mut E,
+    tty: bool,
+    buffer: Vec<u8>,
+}
+
+impl<'a, O: Write + ?Sized, E: Write + ?Sized> Demultiplexer<'a, O, E> {
+    pub fn new(stdout: &'a mut O, stderr: &'a mut E, tty: bool) -> Self {
+        Demultiplexer { stdout, stderr, tty, buffer: vec![] }
+    }
+}
+
+impl<O: Write + ?Sized, E: Write + ?Sized> Write for Demultiplexer<'_, O, E> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        if self.tty {
+            self.stdout.write_all(bytes)?;
+            return Ok(bytes.len());
+        }
+
+        self.buffer.extend_from_slice(bytes);
+        while self.buffer.len() >= HEADER_LEN {
+            let stream = match header(&self.buffer) {
+                Some(stream) => stream,
+                None => {
+                    self.tty = true;
+                    self.stdout.write_all(&self.buffer)?;
+                    self.buffer.clear();
+                    break;
+                }
+            };
+
+            let size = u32::from_be_bytes([self.buffer[4], self.buffer[5], self.buffer[6], self.buffer[7]]) as usize;
+            if self.buffer.len() < HEADER_LEN + size {
+                break;
+            }
+
+            match stream {
+                StreamType::Stderr => self.stderr.write_all(&self.buffer[HEADER_LEN..HEADER_LEN + size])?,
+                _ => self.stdout.write_all(&self.buffer[HEADER_LEN..HEADER_LEN + size])?,
+            }
+            self.buffer.drain(..HEADER_LEN + size);
+        }
+
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.stdout.flush()?;
+        self.stderr.flush()
+    }
+}
+
+fn header(bytes: &[u8]) -> Option<StreamType> {
+    if bytes.len() < HEADER_LEN || bytes[1..4] != [0, 0, 0] {
+        return None;
+    }
+
+    StreamType::from_byte(bytes[0])
+}
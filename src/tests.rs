@@ -3,6 +3,33 @@ use crate::container_create::{CreateContainerFrom};
 #[allow(unused_imports)]
 use crate::{container_network::{HostConfig, NetworkingConfig}};
 use crate::containers_service::ContainersServiceTrait;
+use crate::container_changes::{ChangeKind, FilesystemChange};
+use crate::signal::Signal;
+use crate::container_health::{HealthConfig, HealthTest};
+use crate::container_spec::ContainerSpec;
+use crate::container_ports::{Protocol, PortSpec, parse_port_specs};
+use crate::units::{parse_bytes, parse_cpus, parse_cpuset};
+use crate::container_resources::Resources;
+use crate::container_mounts::{Relabel, VolumeSpec, parse_mount};
+use crate::container_network::{EndpointSettings, IPAMConfig};
+use crate::container_host_config::{HostConfig as CreateHostConfig, PortBinding, RestartPolicy};
+use crate::container_inspect::InspectedContainer;
+use crate::container_resolve::ContainerReference;
+use crate::container_procceses::ContainerProcessesResponse;
+use crate::container_state::{ContainerState, HealthStatus};
+use crate::container_structs::{Container, GenericDevice};
+use crate::container_devices::{validate_device_cgroup_rule, validate_devices};
+use crate::container_prune::{PruneFilters, PruneReport};
+use crate::container_archive::{PathStat, archive_host_path, extract_archive};
+use crate::container_wait::{WaitCondition, WaitResponse};
+use crate::exec_service::ExecServiceTrait;
+use crate::exec_structs::{ExecConfig, ExecStartConfig};
+use crate::stream::{demultiplex, frames, Demultiplexer, StreamType};
+use crate::container_run::{RunOptions, pull_reference};
+use crate::container_ready::{Condition, LineMatcher, probe_tcp, tcp_addresses};
+use crate::container_filters::ContainerFilters;
+use crate::container_update::{ThrottleDeviceRate, UpdateContainerWith};
+use std::io::Write as _;
 
 #[test]
 fn test() {
@@ -12,7 +39,7 @@ fn test() {
 #[test]
 fn get_containers() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
-    match client.containers.list_containers(false, 0, false, "".to_string()) {
+    match client.containers.list_containers(false, 0, false, &ContainerFilters::default()) {
         Ok(_) => {},
         Err(e) => panic!("Error: {}", e)
     };
@@ -40,6 +67,10 @@ fn create_container() {
         volumes: None,
         working_dir: None,
         entrypoint: None,
+        healthcheck: None,
+        args_escaped: None,
+        on_build: None,
+        shell: None,
         network_disabled: None,
         mac_address: None,
         stop_signal: None,
@@ -53,30 +84,34 @@ fn create_container() {
         Err(e) => panic!("Error: {}", e)
     };
 
-    assert!(response.id.len() > 0);
+    assert!(!response.id.is_empty());
 }
 
 #[test]
 fn create_container_short() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
-    let mut options = CreateContainerFrom::default();
-    options.image = Some("alpine:latest".to_string());
-    options.cmd = Some(vec!["/bin/true".to_string()]);
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["/bin/true".to_string()]),
+        ..Default::default()
+    };
 
     let response = match client.containers.create_container("test2", "linux", &options) {
         Ok(response) => response,
         Err(e) => panic!("Error: {}", e)
     };
 
-    assert!(response.id.len() > 0);
+    assert!(!response.id.is_empty());
 }
 
 #[test]
 fn create_and_remove_container() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
-    let mut options = CreateContainerFrom::default();
-    options.image = Some("alpine:latest".to_string());
-    options.cmd = Some(vec!["/bin/true".to_string()]);
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["/bin/true".to_string()]),
+        ..Default::default()
+    };
 
     let response = match client.containers.create_container("test3", "linux", &options) {
         Ok(response) => response,
@@ -92,9 +127,11 @@ fn create_and_remove_container() {
 #[test]
 fn create_start_getstats_stop_remove() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
-    let mut options = CreateContainerFrom::default();
-    options.image = Some("alpine:latest".to_string());
-    options.cmd = Some(vec!["/bin/true".to_string()]);
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["/bin/true".to_string()]),
+        ..Default::default()
+    };
 
     let response = match client.containers.create_container("test4", "linux", &options) {
         Ok(response) => response,
@@ -113,7 +150,7 @@ fn create_start_getstats_stop_remove() {
         Err(e) => panic!("Error: {}", e)
     };
 
-    match client.containers.stop_container(&response.id, 0) {
+    match client.containers.stop_container(&response.id, None, Some(0)) {
         Ok(_) => {},
         Err(e) => panic!("Error: {}", e)
     };
@@ -128,7 +165,7 @@ fn create_start_getstats_stop_remove() {
 fn get_stats() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
     // get first a container id
-    let containers = match client.containers.list_containers(true, 0, false, "".to_string()) {
+    let containers = match client.containers.list_containers(true, 0, false, &ContainerFilters::default()) {
         Ok(containers) => containers,
         Err(e) => panic!("Error: {}", e)
     };
@@ -145,7 +182,7 @@ fn get_stats() {
 fn inspect_container() {
     let mut client = Client::new("/var/run/docker.sock".to_string());
     // get first a container id
-    let containers = match client.containers.list_containers(true, 0, false, "".to_string()) {
+    let containers = match client.containers.list_containers(true, 0, false, &ContainerFilters::default()) {
         Ok(containers) => containers,
         Err(e) => panic!("Error: {}", e)
     };
@@ -156,4 +193,1022 @@ fn inspect_container() {
         Ok(container) => container,
         Err(e) => panic!("Error: {}", e)
     };
-}
\ No newline at end of file
+}
+
+#[test]
+fn create_start_inspect_exec() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sleep".to_string(), "30".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test5", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.start_container(&response.id) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    let config = ExecConfig {
+        attach_stdout: Some(true),
+        attach_stderr: Some(true),
+        cmd: Some(vec!["echo".to_string(), "hello".to_string()]),
+        ..Default::default()
+    };
+
+    let exec = match client.exec.create_exec(&response.id, &config) {
+        Ok(exec) => exec,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.exec.start_exec(&exec.id, &ExecStartConfig::default()) {
+        Ok(output) => assert!(!output.is_empty()),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.exec.inspect_exec(&exec.id) {
+        Ok(inspected) => assert_eq!(inspected.container_id, response.id),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.list_processes(&response.id, Some("-o pid,ppid,args")) {
+        Ok(processes) => assert_eq!(processes.records()[0].command.as_deref(), Some("sleep 30")),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn demultiplex_stream() {
+    let mut bytes = vec![1, 0, 0, 0, 0, 0, 0, 3];
+    bytes.extend_from_slice(b"out");
+    bytes.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 3]);
+    bytes.extend_from_slice(b"err");
+    bytes.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 1]);
+    bytes.extend_from_slice(b"\n");
+
+    let parsed = frames(&bytes);
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[1].stream, StreamType::Stderr);
+
+    let (stdout, stderr) = demultiplex(&bytes);
+    assert_eq!(stdout, b"out\n");
+    assert_eq!(stderr, b"err");
+}
+
+#[test]
+fn demultiplex_tty_stream() {
+    let (stdout, stderr) = demultiplex(b"hello from a tty\n");
+    assert_eq!(stdout, b"hello from a tty\n");
+    assert!(stderr.is_empty());
+}
+
+#[test]
+fn run_in_container() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sleep".to_string(), "30".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test6", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.start_container(&response.id) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    let cmd = vec!["sh".to_string(), "-c".to_string(), "cat; echo oops >&2; exit 3".to_string()];
+    match client.exec.run_in_container(&response.id, cmd, Some(b"hello".to_vec()), Some(std::time::Duration::from_secs(10))) {
+        Ok(output) => {
+            assert_eq!(output.exit_code, 3);
+            assert_eq!(output.stdout, "hello");
+            assert_eq!(output.stderr, "oops\n");
+        },
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn wait_response() {
+    let response: WaitResponse = serde_json::from_str(r#"{"StatusCode": 137, "Error": {"Message": "killed"}}"#).unwrap();
+    assert_eq!(response.status_code, 137);
+    assert_eq!(response.error.unwrap().message, "killed");
+    assert_eq!(WaitCondition::NextExit.to_string(), "next-exit");
+}
+
+#[test]
+fn create_start_wait_remove() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sh".to_string(), "-c".to_string(), "exit 7".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test7", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.start_container(&response.id) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.wait_container_timeout(&response.id, WaitCondition::NotRunning, std::time::Duration::from_secs(10)) {
+        Ok(status) => assert_eq!(status.status_code, 7),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn filesystem_changes() {
+    let changes: Vec<FilesystemChange> = serde_json::from_str(r#"[{"Path": "/dev", "Kind": 0}, {"Path": "/dev/kmsg", "Kind": 1}, {"Path": "/test", "Kind": 2}]"#).unwrap();
+    assert_eq!(changes[0].kind, ChangeKind::Modified);
+    assert_eq!(changes[1].kind, ChangeKind::Added);
+    assert_eq!(changes[2].kind, ChangeKind::Deleted);
+    assert!(serde_json::from_str::<FilesystemChange>(r#"{"Path": "/x", "Kind": 9}"#).is_err());
+}
+
+#[test]
+fn create_export_remove() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["/bin/true".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test8", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    let mut tarball: Vec<u8> = vec![];
+    match client.containers.export_container(&response.id, &mut tarball) {
+        Ok(_) => assert!(tarball.len() > 512),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn export_container_async_in_runtime() {
+    // The request runs on the runtime of the caller, without the socket it fails instead of panicking on a nested runtime
+    let client = Client::new("/nonexistent/docker.sock".to_string());
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut tarball: Vec<u8> = vec![];
+    let result = runtime.block_on(client.containers.export_container_async("missing", &mut tarball));
+    assert!(result.is_err());
+    assert!(tarball.is_empty());
+}
+
+#[test]
+fn services_are_object_safe() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let _containers: &mut dyn ContainersServiceTrait = &mut client.containers;
+    let _exec: &mut dyn ExecServiceTrait = &mut client.exec;
+}
+
+#[test]
+fn path_stat_header() {
+    // {"name":"etc","size":4096,"mode":2147484141,"mtime":"2023-01-09T21:11:03Z","linkTarget":""}
+    let header = b"eyJuYW1lIjoiZXRjIiwic2l6ZSI6NDA5NiwibW9kZSI6MjE0NzQ4NDE0MSwibXRpbWUiOiIyMDIzLTAxLTA5VDIxOjExOjAzWiIsImxpbmtUYXJnZXQiOiIifQ==";
+    let stat = PathStat::from_header(header).unwrap();
+    assert_eq!(stat.name, "etc");
+    assert!(stat.is_dir());
+    assert_eq!(stat.permissions(), 0o755);
+}
+
+#[test]
+fn archive_and_extract_host_path() {
+    let root = std::env::temp_dir().join(format!("docker-engine-api-archive-{}", std::process::id()));
+    let source = root.join("source");
+    std::fs::create_dir_all(source.join("nested")).unwrap();
+    std::fs::write(source.join("nested").join("file.txt"), b"hello").unwrap();
+
+    let archive = archive_host_path(&source).unwrap();
+    extract_archive(archive.as_slice(), &root.join("extracted")).unwrap();
+    assert_eq!(std::fs::read(root.join("extracted").join("source").join("nested").join("file.txt")).unwrap(), b"hello");
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn extract_archive_rejects_traversal() {
+    let mut header = tar::Header::new_old();
+    header.as_old_mut().name[..11].copy_from_slice(b"../evil.txt");
+    header.set_size(4);
+    header.set_mode(0o644);
+    header.set_cksum();
+
+    let mut builder = tar::Builder::new(vec![]);
+    builder.append(&header, &b"evil"[..]).unwrap();
+    let archive = builder.into_inner().unwrap();
+
+    let dst = std::env::temp_dir().join(format!("docker-engine-api-traversal-{}", std::process::id()));
+    let result = extract_archive(archive.as_slice(), &dst);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    assert!(!dst.parent().unwrap().join("evil.txt").exists());
+
+    std::fs::remove_dir_all(&dst).unwrap();
+}
+
+#[test]
+fn copy_to_and_from_container() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["/bin/true".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test9", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    let root = std::env::temp_dir().join(format!("docker-engine-api-copy-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("copied.txt"), b"hello").unwrap();
+
+    match client.containers.copy_to_container(&response.id, &root.join("copied.txt"), "/tmp") {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.stat_container_path(&response.id, "/tmp/copied.txt") {
+        Ok(stat) => assert_eq!(stat.size, 5),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.stat_container_path(&response.id, "/tmp/missing.txt") {
+        Ok(_) => panic!("Expected a missing path"),
+        Err(e) => assert_eq!(e.downcast_ref::<std::io::Error>().unwrap().kind(), std::io::ErrorKind::NotFound),
+    };
+
+    match client.containers.copy_from_container(&response.id, "/tmp/copied.txt", &root.join("back")) {
+        Ok(_) => assert_eq!(std::fs::read(root.join("back").join("copied.txt")).unwrap(), b"hello"),
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    std::fs::remove_dir_all(&root).unwrap();
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn prune_filters_and_report() {
+    let filters = PruneFilters {
+        until: Some("24h".to_string()),
+        labels: vec!["ci=true".to_string()],
+        without_labels: vec![],
+    };
+    let json: serde_json::Value = serde_json::from_str(&filters.to_json().unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({"until": ["24h"], "label": ["ci=true"]}));
+
+    let report: PruneReport = serde_json::from_str(r#"{"ContainersDeleted": null, "SpaceReclaimed": 0}"#).unwrap();
+    assert!(report.containers_deleted.is_empty());
+}
+
+#[test]
+fn parse_signals() {
+    assert_eq!("SIGHUP".parse::<Signal>().unwrap(), Signal::SIGHUP);
+    assert_eq!("usr1".parse::<Signal>().unwrap(), Signal::SIGUSR1);
+    assert_eq!("9".parse::<Signal>().unwrap(), Signal::SIGKILL);
+    assert_eq!("SIGRTMIN+3".parse::<Signal>().unwrap().number(), 37);
+    assert_eq!(Signal::from_number(64).unwrap().name(), "SIGRTMAX");
+    assert!("SIGFOO".parse::<Signal>().is_err());
+    assert!("0".parse::<Signal>().is_err());
+    assert!("SIGRTMIN+31".parse::<Signal>().is_err());
+}
+
+#[test]
+fn serialize_host_config() {
+    let host_config = CreateHostConfig {
+        binds: Some(vec!["/tmp:/data:ro".to_string()]),
+        restart_policy: Some(RestartPolicy::OnFailure(3)),
+        memory: Some(512 * 1024 * 1024),
+        auto_remove: Some(true),
+        port_bindings: Some([("80/tcp".to_string(), vec![PortBinding { host_ip: String::new(), host_port: "8080".to_string() }])].into_iter().collect()),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_value(&host_config).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "Binds": ["/tmp:/data:ro"],
+        "RestartPolicy": {"Name": "on-failure", "MaximumRetryCount": 3},
+        "Memory": 536870912,
+        "AutoRemove": true,
+        "PortBindings": {"80/tcp": [{"HostIp": "", "HostPort": "8080"}]},
+    }));
+
+    let parsed: CreateHostConfig = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.restart_policy, Some(RestartPolicy::OnFailure(3)));
+}
+
+#[test]
+fn networking_config_round_trip() {
+    let mut networking_config = NetworkingConfig::default();
+    networking_config.endpoints_config.insert("backend".to_string(), EndpointSettings {
+        ipam_config: Some(IPAMConfig { ipv4_address: "172.20.0.5".to_string(), ..Default::default() }),
+        aliases: vec!["db".to_string()],
+        ..Default::default()
+    });
+
+    let json = serde_json::to_value(&networking_config).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "EndpointsConfig": {"backend": {"IPAMConfig": {"IPv4Address": "172.20.0.5"}, "Aliases": ["db"]}}
+    }));
+
+    let inspected: NetworkingConfig = serde_json::from_str(r#"{"EndpointsConfig": {"backend": {
+        "IPAMConfig": {"IPv4Address": "172.20.0.5"}, "Links": null, "Aliases": ["db"], "DriverOpts": null,
+        "NetworkID": "4f3c", "IPAddress": "172.20.0.5", "IPPrefixLen": 16
+    }}}"#).unwrap();
+    let endpoint = &inspected.endpoints_config["backend"];
+    assert_eq!(endpoint.ipam_config, networking_config.endpoints_config["backend"].ipam_config);
+    assert_eq!(endpoint.ip_prefix_len, 16);
+    assert!(endpoint.links.is_empty());
+}
+
+#[test]
+fn parse_sizes() {
+    assert_eq!(parse_bytes("512m").unwrap(), 512 * 1024 * 1024);
+    assert_eq!(parse_bytes("2G").unwrap(), 2 * 1024 * 1024 * 1024);
+    assert_eq!(parse_bytes("1.5kib").unwrap(), 1536);
+    assert_eq!(parse_bytes("100").unwrap(), 100);
+    assert!(parse_bytes("12x").is_err());
+    assert!(parse_bytes("m").is_err());
+}
+
+#[test]
+fn resource_limits() {
+    assert_eq!(parse_cpus("1.5").unwrap(), 1_500_000_000);
+    assert!(parse_cpus("0").is_err());
+    assert!(parse_cpus("two").is_err());
+    assert_eq!(parse_cpuset("0-2,5,1").unwrap(), vec![0, 1, 2, 5]);
+    assert!(parse_cpuset("3-1").is_err());
+    assert!(parse_cpuset("0,,1").is_err());
+
+    let resources = Resources {
+        memory: Some("512m".to_string()),
+        memory_swap: Some("1g".to_string()),
+        cpus: Some(1.5),
+        cpuset_cpus: Some("0-3".to_string()),
+        ..Default::default()
+    };
+    let update = resources.to_update().unwrap();
+    assert_eq!(update.memory, Some(512 * 1024 * 1024));
+    assert_eq!(update.memory_swap, Some(1024 * 1024 * 1024));
+    assert_eq!(update.nano_cpus, Some(1_500_000_000));
+    assert_eq!((update.cpu_quota, update.cpu_period), (None, None));
+    assert_eq!(update.cpuset_cpus, Some("0-3".to_string()));
+
+    let quota = Resources { cpus: Some(0.5), cpu_period: Some(100_000), ..Default::default() }.to_update().unwrap();
+    assert_eq!((quota.nano_cpus, quota.cpu_quota, quota.cpu_period), (None, Some(50_000), Some(100_000)));
+
+    let unlimited_swap = Resources { memory: Some("1g".to_string()), memory_swap: Some("-1".to_string()), ..Default::default() };
+    assert_eq!(unlimited_swap.to_update().unwrap().memory_swap, Some(-1));
+
+    let swap_below_memory = Resources { memory: Some("1g".to_string()), memory_swap: Some("512m".to_string()), ..Default::default() };
+    assert!(swap_below_memory.to_update().is_err());
+    assert!(Resources { memory: Some("1m".to_string()), ..Default::default() }.to_update().is_err());
+    assert!(Resources { cpus: Some(0.001), cpu_period: Some(100_000), ..Default::default() }.to_update().is_err());
+    assert!(Resources { cpuset_mems: Some("a-b".to_string()), ..Default::default() }.to_update().is_err());
+
+    let options = ContainerSpec::new()
+        .with_host_config(|host_config| host_config.cpu_quota = Some(20_000))
+        .resources(&resources)
+        .build()
+        .unwrap();
+    let host_config = options.host_config.unwrap();
+    assert_eq!((host_config.nano_cpus, host_config.cpu_quota), (Some(1_500_000_000), None));
+    assert_eq!(host_config.memory_swap, Some(1024 * 1024 * 1024));
+    assert!(ContainerSpec::new().resources(&swap_below_memory).build().is_err());
+
+    // In a host config, the limits are checked against the memory limit already set, and swap needs one
+    let swap = Resources { memory_swap: Some("512m".to_string()), ..Default::default() };
+    let reservation = Resources { memory_reservation: Some("2g".to_string()), ..Default::default() };
+    assert!(ContainerSpec::new().resources(&swap).build().is_err());
+    assert!(ContainerSpec::new().memory("1g").resources(&swap).build().is_err());
+    assert!(ContainerSpec::new().memory("1g").resources(&reservation).build().is_err());
+    assert!(ContainerSpec::new().memory("256m").resources(&swap).build().is_ok());
+    let unlimited = Resources { memory_swap: Some("-1".to_string()), ..Default::default() };
+    assert!(ContainerSpec::new().resources(&unlimited).build().is_ok());
+
+    let mut host_config = CreateHostConfig { memory: Some(1024 * 1024 * 1024), ..Default::default() };
+    assert!(swap.apply(&mut host_config).is_err());
+    assert_eq!(host_config.memory_swap, None);
+    assert!(swap.to_update().is_ok());
+}
+
+#[test]
+fn volume_and_mount_specs() {
+    let bind: VolumeSpec = "/src:/dst:ro,z".parse().unwrap();
+    assert_eq!((bind.mount._type.as_str(), bind.mount.source.as_str(), bind.mount.target.as_str()), ("bind", "/src", "/dst"));
+    assert_eq!((bind.mount.read_only, bind.relabel), (Some(true), Some(Relabel::Shared)));
+    assert_eq!(bind.bind(), Some("/src:/dst:ro,z".to_string()));
+
+    let volume: VolumeSpec = "named-vol:/data:nocopy".parse().unwrap();
+    assert_eq!((volume.mount._type.as_str(), volume.mount.source.as_str()), ("volume", "named-vol"));
+    assert_eq!(volume.mount.volume_options.unwrap().no_copy, Some(true));
+
+    let anonymous: VolumeSpec = "/cache".parse().unwrap();
+    assert_eq!((anonymous.mount._type.as_str(), anonymous.bind()), ("volume", None));
+
+    for spec in ["./src:/dst", "src/dir:/dst", "/src:dst", "/src:/dst:ro,rw", "/src:/dst:nocopy", "vol:/data:rshared", "/src:/dst:bogus", "/dst:ro", "a:b:c:d", "/src:/"] {
+        assert!(spec.parse::<VolumeSpec>().is_err(), "{}", spec);
+    }
+
+    let tmpfs = parse_mount("type=tmpfs,target=/cache,tmpfs-size=64m,tmpfs-mode=1770").unwrap();
+    let tmpfs_options = tmpfs.tmpfs_options.unwrap();
+    assert_eq!((tmpfs_options.size_bytes, tmpfs_options.mode), (Some(64 * 1024 * 1024), Some(0o1770)));
+
+    let volume = parse_mount("src=data,dst=/data,readonly,volume-opt=type=nfs,volume-label=team=web").unwrap();
+    assert_eq!((volume._type.as_str(), volume.read_only), ("volume", Some(true)));
+    let volume_options = volume.volume_options.unwrap();
+    assert_eq!(volume_options.labels.unwrap()["team"], "web");
+    assert_eq!(volume_options.driver_config.unwrap().options["type"], "nfs");
+
+    let bind = parse_mount("type=bind,source=/srv,target=/srv,bind-propagation=rslave,ro=false").unwrap();
+    assert_eq!((bind.read_only, bind.bind_options.unwrap().propagation), (Some(false), Some("rslave".to_string())));
+
+    for spec in ["type=bind,source=srv,target=/srv", "type=tmpfs,source=/tmp,target=/tmp", "type=bind,source=/srv,target=/srv,volume-nocopy",
+        "type=volume,target=/data,tmpfs-size=1m", "target=relative", "source=data", "type=nfs,target=/data", "target=/data,colour=red", "target=/data,readonly=maybe"] {
+        assert!(parse_mount(spec).is_err(), "{}", spec);
+    }
+
+    let inspected: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    for mount in &inspected.mounts {
+        let spec = crate::container_host_config::MountSpec::from(mount);
+        assert_eq!((spec._type.as_str(), spec.target.as_str(), spec.read_only), (mount._type.as_str(), mount.destination.as_str(), Some(!mount.rw)));
+    }
+
+    let options = ContainerSpec::new().volume("/srv:/data:ro").volume("/cache").mount("type=tmpfs,target=/run").build().unwrap();
+    let host_config = options.host_config.unwrap();
+    assert_eq!(host_config.binds, Some(vec!["/srv:/data:ro".to_string()]));
+    assert_eq!(host_config.mounts.unwrap().len(), 2);
+    assert!(ContainerSpec::new().volume("relative:/data:rw,rw").build().is_err());
+}
+
+#[test]
+fn device_mappings() {
+    let device: GenericDevice = "/dev/sdc:/dev/xvdc:rwm".parse().unwrap();
+    assert_eq!(device, GenericDevice { host_path: "/dev/sdc".to_string(), container_path: "/dev/xvdc".to_string(), cgroup_permissions: "rwm".to_string() });
+    assert_eq!(serde_json::to_value(&device).unwrap(), serde_json::json!({"PathOnHost": "/dev/sdc", "PathInContainer": "/dev/xvdc", "CgroupPermissions": "rwm"}));
+    assert_eq!("/dev/fuse".parse::<GenericDevice>().unwrap().to_string(), "/dev/fuse:/dev/fuse:rwm");
+    assert_eq!("/dev/snd:r".parse::<GenericDevice>().unwrap().to_string(), "/dev/snd:/dev/snd:r");
+    for spec in ["dev/sdc", "/dev/sdc:xvdc", "/dev/sdc:/dev/xvdc:rwx", "/dev/sdc:/dev/xvdc:rr", "/dev/sdc:/dev/xvdc:", "/a:/b:r:w"] {
+        assert!(spec.parse::<GenericDevice>().is_err(), "{}", spec);
+    }
+
+    for rule in ["c 1:3 mr", "b 8:* rwm", "a *:* rwm", "c 1:3 rr", "c 1:3 mmm"] {
+        assert!(validate_device_cgroup_rule(rule).is_ok(), "{}", rule);
+    }
+    for rule in ["x 1:3 r", "c 1 r", "c 1:3", "c a:3 r", "c 1:3 rwmr", "c 1:3 rx", "c  1:3 r"] {
+        assert!(validate_device_cgroup_rule(rule).is_err(), "{}", rule);
+    }
+
+    let update = UpdateContainerWith {
+        devices: Some(vec![device.clone()]),
+        device_cgroup_rules: Some(vec!["c 1:3 mr".to_string()]),
+        ..Default::default()
+    };
+    assert!(validate_devices(update.devices.as_deref().unwrap(), update.device_cgroup_rules.as_deref().unwrap()).is_ok());
+    assert_eq!(serde_json::to_value(&update).unwrap()["Devices"][0]["CgroupPermissions"], "rwm");
+    assert!(validate_devices(&[], &["c 1:3 rwx".to_string()]).is_err());
+
+    // Windows devices are not paths, the Engine checks them
+    let windows = GenericDevice { host_path: "class/5B45201D-F2F2-4F3B-85BB-30FF1F953599".to_string(), ..Default::default() };
+    assert!(windows.validate().is_err());
+    assert!(validate_devices(&[windows], &[]).is_ok());
+    let duplicated = GenericDevice { cgroup_permissions: "rr".to_string(), ..device.clone() };
+    assert!(validate_devices(&[duplicated], &[]).is_err());
+
+    let host_config: CreateHostConfig = serde_json::from_value(serde_json::json!({
+        "Devices": [{"PathOnHost": "/dev/fuse", "PathInContainer": "/dev/fuse", "CgroupPermissions": "rwm"}],
+        "DeviceCgroupRules": ["c 10:229 rwm"]
+    })).unwrap();
+    assert_eq!(host_config.devices.unwrap()[0].cgroup_permissions, "rwm");
+    assert_eq!(host_config.device_cgroup_rules, Some(vec!["c 10:229 rwm".to_string()]));
+
+    let options = ContainerSpec::new().device("/dev/fuse").device_cgroup_rule("c 10:229 rwm").build().unwrap();
+    assert_eq!(options.host_config.unwrap().devices.unwrap()[0].container_path, "/dev/fuse");
+    assert!(ContainerSpec::new().device("/dev/fuse:/dev/fuse:x").build().is_err());
+    assert!(ContainerSpec::new().device_cgroup_rule("c 10 rwm").build().is_err());
+}
+
+#[test]
+fn build_container_spec() {
+    let options = ContainerSpec::new()
+        .image("alpine:latest")
+        .cmd(&["sleep", "30"])
+        .env("MODE", "test")
+        .label("team", "web")
+        .expose(53, Protocol::Udp)
+        .publish(8080, 80)
+        .bind("/srv", "/data", true)
+        .memory("512m")
+        .restart(RestartPolicy::OnFailure(3))
+        .build()
+        .unwrap();
+
+    assert_eq!(options.env, Some(vec!["MODE=test".to_string()]));
+    let exposed = options.exposed_ports.unwrap();
+    assert!(exposed.contains_key("53/udp") && exposed.contains_key("80/tcp"));
+
+    let host_config = options.host_config.unwrap();
+    assert_eq!(host_config.port_bindings.unwrap()["80/tcp"][0].host_port, "8080");
+    assert_eq!(host_config.binds, Some(vec!["/srv:/data:ro".to_string()]));
+    assert_eq!(host_config.memory, Some(512 * 1024 * 1024));
+    assert_eq!(host_config.restart_policy, Some(RestartPolicy::OnFailure(3)));
+
+    assert!(ContainerSpec::new().memory("lots").build().is_err());
+}
+
+#[test]
+fn serialize_healthcheck_and_entrypoint() {
+    let options = ContainerSpec::new()
+        .image("alpine:latest")
+        .entrypoint(&["/bin/sh", "-c"])
+        .stop_signal(Signal::SIGINT)
+        .healthcheck(HealthConfig {
+            test: Some(HealthTest::CmdShell("wget -q -O- localhost || exit 1".to_string())),
+            interval: Some(std::time::Duration::from_secs(5)),
+            retries: Some(3),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_value(&options).unwrap();
+    assert_eq!(json["Entrypoint"], serde_json::json!(["/bin/sh", "-c"]));
+    assert_eq!(json["StopSignal"], "SIGINT");
+    assert_eq!(json["Healthcheck"], serde_json::json!({"Test": ["CMD-SHELL", "wget -q -O- localhost || exit 1"], "Interval": 5000000000u64, "Retries": 3}));
+
+    let healthcheck: HealthConfig = serde_json::from_value(json["Healthcheck"].clone()).unwrap();
+    assert_eq!(healthcheck, options.healthcheck.unwrap());
+
+    let too_short = HealthConfig { timeout: Some(std::time::Duration::from_micros(10)), ..Default::default() };
+    assert!(too_short.validate().is_err());
+    assert!(serde_json::from_str::<CreateContainerFrom>(r#"{"StopSignal": "SIGNOPE"}"#).is_err());
+}
+
+#[test]
+fn inspect_fixtures() {
+    let exited: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.41.json")).unwrap();
+    assert_eq!(exited.state.status, ContainerState::Exited);
+    assert_eq!(exited.state.exit_code, 9);
+    assert!(exited.exec_ids.is_empty());
+    assert!(exited.config.entrypoint.is_empty());
+    assert_eq!(exited.size_root_fs, Some(7049717));
+    assert_eq!(exited.host_config.restart_policy, Some(RestartPolicy::No));
+    assert!(exited.network_settings.networks.contains_key("bridge"));
+
+    let running: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    assert_eq!(running.name, "/web");
+    assert_eq!(running.exec_ids.len(), 1);
+    assert_eq!(running.state.health.as_ref().unwrap().status, HealthStatus::Healthy);
+    assert_eq!(running.state.health.as_ref().unwrap().log.len(), 2);
+    assert_eq!(running.state.health.as_ref().unwrap().log[1].exit_code, 0);
+    assert_eq!(running.host_config.restart_policy, Some(RestartPolicy::OnFailure(5)));
+    assert_eq!(running.host_config.memory, Some(536870912));
+    assert_eq!(running.host_config.oom_kill_disable, None);
+    assert_eq!(running.host_config.port_bindings.as_ref().unwrap()["80/tcp"], vec![PortBinding { host_ip: String::new(), host_port: "8080".to_string() }]);
+    assert!(running.config.exposed_ports.contains_key("80/tcp"));
+    assert_eq!(running.config.stop_timeout, Some(20));
+    let healthcheck = running.config.healthcheck.as_ref().unwrap();
+    assert!(matches!(healthcheck.test, Some(HealthTest::CmdShell(_))));
+    assert_eq!(healthcheck.interval, Some(std::time::Duration::from_secs(30)));
+    assert_eq!(running.network_settings.ports["443/tcp"], None);
+    assert_eq!(running.network_settings.ports["80/tcp"].as_ref().unwrap().len(), 2);
+    assert_eq!(running.network_settings.networks["frontend"].ip_prefix_len, 16);
+
+    let paused: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.47.json")).unwrap();
+    assert!(paused.state.paused);
+    assert_eq!(paused.state.status, ContainerState::Paused);
+    assert!(paused.state.status.is_running());
+    assert_eq!(paused.state.health.as_ref().unwrap().status, HealthStatus::Unhealthy);
+    assert_eq!(paused.state.health.as_ref().unwrap().log[0].exit_code, -1);
+    assert_eq!(paused.state.health.as_ref().unwrap().failing_streak, 4);
+    assert_eq!(paused.host_config.kernel_memory, None);
+    assert_eq!(paused.host_config.annotations.as_ref().unwrap()["io.example.owner"], "data");
+    assert!(paused.config.labels.is_empty());
+    assert_eq!(paused.network_settings.networks["backend"].dns_names, vec!["db", "e90e34656806"]);
+}
+
+#[test]
+fn container_states() {
+    let container: Container = serde_json::from_str(r#"{"Id": "abc", "State": "running", "Status": "Up 3 seconds (health: starting)"}"#).unwrap();
+    assert_eq!(container.state, ContainerState::Running);
+    assert_eq!(container.health(), HealthStatus::Starting);
+
+    let container: Container = serde_json::from_str(r#"{"Id": "abc", "State": "hibernating", "Status": "Exited (0) 5 seconds ago"}"#).unwrap();
+    assert_eq!(container.state, ContainerState::Unknown("hibernating".to_string()));
+    assert_eq!(container.health(), HealthStatus::None);
+    assert_eq!(serde_json::to_value(&container.state).unwrap(), "hibernating");
+    assert_eq!("unhealthy".parse::<HealthStatus>().unwrap(), HealthStatus::Unhealthy);
+}
+
+#[test]
+fn raw_timestamps() {
+    let running: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    assert_eq!(running.state.finished_at, crate::timestamp::ZERO_TIME);
+    assert_eq!(serde_json::to_value(&running.state).unwrap()["FinishedAt"], crate::timestamp::ZERO_TIME);
+
+    let container: Container = serde_json::from_str(r#"{"Id": "abc", "Created": 1700000000}"#).unwrap();
+    assert_eq!(container.created, 1700000000);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn parse_timestamps() {
+    let running: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    assert!(running.created_time().unwrap() < running.state.started_time().unwrap());
+    assert_eq!(running.state.finished_time(), None);
+    assert!(running.state.uptime().unwrap() > chrono::Duration::zero());
+    assert!(running.state.health.as_ref().unwrap().log[0].end_time().is_some());
+
+    let exited: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.41.json")).unwrap();
+    let uptime = exited.state.finished_time().unwrap() - exited.state.started_time().unwrap();
+    assert_eq!(exited.state.uptime(), Some(uptime));
+
+    let container: Container = serde_json::from_str(r#"{"Id": "abc", "Created": 1700000000}"#).unwrap();
+    assert_eq!(container.created_time().unwrap().timestamp(), 1700000000);
+    assert_eq!(Container::default().created_time(), None);
+    assert!(crate::timestamp::parse("yesterday").is_err());
+}
+
+#[test]
+fn process_records_and_tree() {
+    let response: ContainerProcessesResponse = serde_json::from_str(r#"{
+        "Titles": ["UID", "PID", "PPID", "C", "STIME", "TTY", "TIME", "CMD"],
+        "Processes": [
+            ["root", "4100", "4080", "0", "10:00", "?", "00:00:00", "nginx: master process nginx -g daemon off;"],
+            ["101", "4151", "4100", "2", "10:00", "?", "00:00:01", "nginx: worker process"],
+            ["101", "4152", "4100", "0", "10:00", "?", "00:00:00", "nginx: worker process"],
+            ["root", "4200", "4100", "0", "10:01", "?", "00:00:00", "sh -c sleep 60"],
+            ["root", "4201", "4200", "0", "10:01", "?", "00:00:00", "sleep 60"]
+        ]
+    }"#).unwrap();
+
+    let records = response.records();
+    assert_eq!(records[1].pid, Some(4151));
+    assert_eq!(records[1].ppid, Some(4100));
+    assert_eq!(records[1].cpu, Some(2.0));
+    assert_eq!(records[1].mem, None);
+    assert_eq!(records[0].command.as_deref(), Some("nginx: master process nginx -g daemon off;"));
+    assert_eq!(records[0].columns["UID"], "root");
+
+    let tree = response.tree();
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].process.pid, Some(4100));
+    assert_eq!(tree[0].children.len(), 3);
+    assert_eq!(tree[0].children[2].children[0].process.command.as_deref(), Some("sleep 60"));
+    assert_eq!(tree[0].flatten().len(), 5);
+
+    let busybox: ContainerProcessesResponse = serde_json::from_str(r#"{"Titles": ["PID", "USER", "TIME", "COMMAND"], "Processes": [["1", "root", "0:00", "sleep 60"], ["7", "root", "0:00", "sh"]]}"#).unwrap();
+    assert_eq!(busybox.tree().len(), 2);
+}
+
+#[test]
+fn resolve_container_references() {
+    let containers: Vec<Container> = serde_json::from_str(r#"[
+        {"Id": "4f1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c", "Names": ["/web"], "Labels": {"app": "shop", "tier": "front"}},
+        {"Id": "4f1c9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c", "Names": ["/api"], "Labels": {"app": "shop", "tier": "back"}},
+        {"Id": "a0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1", "Names": ["/4f1c"], "Labels": {}}
+    ]"#).unwrap();
+    let resolve = |reference: &str| reference.parse::<ContainerReference>().unwrap().resolve(&containers);
+
+    assert_eq!(resolve("web").unwrap()[0].names, vec!["/web"]);
+    assert_eq!(resolve("/api").unwrap()[0].names, vec!["/api"]);
+    assert_eq!(resolve("4f1c9").unwrap()[0].names, vec!["/api"]);
+    assert_eq!(resolve(&containers[0].id).unwrap()[0].names, vec!["/web"]);
+    // A name takes precedence over an id prefix, like the Engine does
+    assert_eq!(resolve("4f1c").unwrap()[0].names, vec!["/4f1c"]);
+    assert!(resolve("4f").unwrap_err().to_string().contains("matches 2 containers"));
+    assert!(resolve("db").is_err());
+
+    assert_eq!(resolve("label=app=shop").unwrap().len(), 2);
+    assert_eq!(resolve("label=tier=back").unwrap()[0].names, vec!["/api"]);
+    assert_eq!(resolve("label=tier").unwrap().len(), 2);
+    assert!(resolve("label=app=blog").is_err());
+    assert!("label==shop".parse::<ContainerReference>().is_err());
+    assert!("".parse::<ContainerReference>().is_err());
+}
+
+#[test]
+fn demultiplex_chunked_stream() {
+    let mut bytes = vec![1, 0, 0, 0, 0, 0, 0, 6];
+    bytes.extend_from_slice(b"hello\n");
+    bytes.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 5]);
+    bytes.extend_from_slice(b"oops\n");
+
+    let mut stdout = vec![];
+    let mut stderr = vec![];
+    let mut demultiplexer = Demultiplexer::new(&mut stdout, &mut stderr, false);
+    for chunk in bytes.chunks(3) {
+        demultiplexer.write_all(chunk).unwrap();
+    }
+    assert_eq!(stdout, b"hello\n");
+    assert_eq!(stderr, b"oops\n");
+
+    let mut stdout = vec![];
+    let mut stderr = vec![];
+    Demultiplexer::new(&mut stdout, &mut stderr, true).write_all(b"plain output").unwrap();
+    assert_eq!(stdout, b"plain output");
+}
+
+#[test]
+fn pull_references() {
+    assert_eq!(pull_reference("alpine"), "alpine:latest");
+    assert_eq!(pull_reference("alpine:3.19"), "alpine:3.19");
+    assert_eq!(pull_reference("localhost:5000/team/app"), "localhost:5000/team/app:latest");
+    assert_eq!(pull_reference("alpine@sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b"), "alpine@sha256:c5b1261d6d3e43071626931fc004f70149baeba2c8ec672bd4f27761f8e1ad6b");
+}
+
+#[test]
+fn run_container() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let config = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sh".to_string(), "-c".to_string(), "echo out; echo err >&2; exit 3".to_string()]),
+        ..Default::default()
+    };
+
+    let options = RunOptions { name: "test10".to_string(), remove: true, ..Default::default() };
+    let output = match client.containers.run_container(&config, &options) {
+        Ok(output) => output,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    assert_eq!(output.exit_code, 3);
+    assert_eq!(output.stdout, "out\n");
+    assert_eq!(output.stderr, "err\n");
+    assert!(client.containers.inspect_container(&output.id, false).is_err());
+}
+
+#[test]
+fn port_specs_and_lookups() {
+    let web: PortSpec = "8080:80".parse().unwrap();
+    assert_eq!(web, PortSpec { host_ip: None, host_ports: Some((8080, 8080)), container_ports: (80, 80), protocol: Protocol::Tcp });
+    let dns: PortSpec = "127.0.0.1::53/udp".parse().unwrap();
+    assert_eq!((dns.host_ip, dns.host_ports), (Some("127.0.0.1".parse().unwrap()), None));
+    assert_eq!(dns.to_string(), "127.0.0.1::53/udp");
+    assert_eq!("[::1]:9000:9000".parse::<PortSpec>().unwrap().to_string(), "[::1]:9000:9000/tcp");
+    assert_eq!("8000-8010:80".parse::<PortSpec>().unwrap().port_bindings()[0].1.host_port, "8000-8010");
+    for spec in ["", "80:", "0", "3000-3005:3000-3001", "::1:80:80", "localhost:80:80", "80/icmp", "1-2-3"] {
+        assert!(spec.parse::<PortSpec>().is_err(), "{}", spec);
+    }
+
+    let (exposed_ports, port_bindings) = parse_port_specs(&["3000-3002:4000-4002", "127.0.0.1::53/udp", "80"]).unwrap();
+    assert_eq!(exposed_ports.len(), 5);
+    assert_eq!(port_bindings["4001/tcp"][0].host_port, "3001");
+    assert_eq!(port_bindings["53/udp"][0].host_ip, "127.0.0.1");
+    assert_eq!(port_bindings["80/tcp"][0].host_port, "");
+
+    let options = ContainerSpec::new().publish_spec("127.0.0.1:8443:443").build().unwrap();
+    assert!(options.exposed_ports.unwrap().contains_key("443/tcp"));
+    assert_eq!(options.host_config.unwrap().host_addresses(443, Protocol::Tcp), vec!["127.0.0.1:8443".parse().unwrap()]);
+    assert!(ContainerSpec::new().publish_spec("80:80:80:80").build().is_err());
+
+    let inspected: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    let published: Vec<std::net::SocketAddr> = vec!["0.0.0.0:8080".parse().unwrap(), "[::]:8080".parse().unwrap()];
+    assert_eq!(inspected.network_settings.host_addresses(80, Protocol::Tcp), published);
+    assert!(inspected.network_settings.host_addresses(443, Protocol::Tcp).is_empty());
+    assert_eq!(inspected.host_config.host_addresses(80, Protocol::Tcp), vec!["0.0.0.0:8080".parse().unwrap()]);
+
+    let listed: Container = serde_json::from_value(serde_json::json!({
+        "Id": "1", "Ports": [{"IP": "0.0.0.0", "PrivatePort": 80, "PublicPort": 49153, "Type": "tcp"}, {"PrivatePort": 443, "Type": "tcp"}]
+    })).unwrap();
+    assert_eq!(listed.host_addresses(80, Protocol::Tcp), vec!["0.0.0.0:49153".parse().unwrap()]);
+    assert!(listed.host_addresses(443, Protocol::Tcp).is_empty());
+    assert!(listed.host_addresses(80, Protocol::Udp).is_empty());
+}
+
+#[test]
+fn readiness_helpers() {
+    let running: InspectedContainer = serde_json::from_str(include_str!("../tests/fixtures/inspect_api_1.43.json")).unwrap();
+    let addresses: Vec<std::net::SocketAddr> = vec!["172.28.0.10:80".parse().unwrap(), "127.0.0.1:8080".parse().unwrap(), "[::1]:8080".parse().unwrap()];
+    assert_eq!(tcp_addresses(&running, 80), addresses);
+    assert_eq!(tcp_addresses(&running, 443), vec!["172.28.0.10:443".parse().unwrap()]);
+    assert!(tcp_addresses(&InspectedContainer::default(), 80).is_empty());
+
+    // A proxy accepting the connection and closing it at once is not ready, a listener keeping it open is
+    let timeout = std::time::Duration::from_secs(1);
+    let closing = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let closing_address = closing.local_addr().unwrap();
+    let proxy = std::thread::spawn(move || drop(closing.accept()));
+    assert!(!probe_tcp(&closing_address, timeout));
+    proxy.join().unwrap();
+    assert!(!probe_tcp(&closing_address, timeout));
+
+    let holding = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    assert!(probe_tcp(&holding.local_addr().unwrap(), timeout));
+
+    let regex = regex::Regex::new(r"listening on port \d+").unwrap();
+    let mut matcher = LineMatcher::new(&regex);
+    assert!(matcher.write_all(b"starting\nlistening on").is_ok());
+    assert!(matcher.write_all(b" port 8080\nmore").is_err());
+    assert!(matcher.matched);
+
+    let mut matcher = LineMatcher::new(&regex);
+    matcher.write_all(b"listening on port 9000").unwrap();
+    assert!(!matcher.matched);
+    assert!(matcher.finish());
+}
+
+#[test]
+fn create_start_wait_until_ready() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sh".to_string(), "-c".to_string(), "sleep 1; echo ready; sleep 30".to_string()]),
+        ..Default::default()
+    };
+
+    let response = match client.containers.create_container("test11", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.start_container(&response.id) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    let timeout = std::time::Duration::from_secs(10);
+    for condition in [Condition::Running, Condition::LogMatches(regex::Regex::new("^ready$").unwrap()), Condition::ExecSucceeds(vec!["true".to_string()])] {
+        match client.wait_until(&response.id, &condition, timeout) {
+            Ok(_) => {},
+            Err(e) => panic!("Error: {}", e)
+        };
+    }
+    assert!(client.wait_until(&response.id, &Condition::Healthy, timeout).is_err());
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn wait_until_delayed_tcp_port() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let options = ContainerSpec::new()
+        .image("alpine:latest")
+        .cmd(&["sh", "-c", "sleep 3; httpd -f -p 8080"])
+        .publish_spec("127.0.0.1::8080")
+        .build()
+        .unwrap();
+
+    let response = match client.containers.create_container("test15", "linux", &options) {
+        Ok(response) => response,
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    match client.containers.start_container(&response.id) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+
+    // The published port is accepted by the proxy from the start, the port is only ready once httpd listens
+    let started = std::time::Instant::now();
+    match client.wait_until(&response.id, &Condition::TcpPort(8080), std::time::Duration::from_secs(15)) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+    assert!(started.elapsed() >= std::time::Duration::from_millis(2500));
+
+    match client.containers.remove_container(&response.id, false, true, false) {
+        Ok(_) => {},
+        Err(e) => panic!("Error: {}", e)
+    };
+}
+
+#[test]
+fn container_filters_json() {
+    assert_eq!(ContainerFilters::default().to_json().unwrap(), "{}");
+
+    let filters = ContainerFilters {
+        labels: vec!["suite=integration".to_string()],
+        status: vec![ContainerState::Exited, ContainerState::Created],
+        exited: vec![0],
+        health: vec![HealthStatus::Unhealthy],
+        ..Default::default()
+    };
+    let json: serde_json::Value = serde_json::from_str(&filters.to_json().unwrap()).unwrap();
+    assert_eq!(json, serde_json::json!({"label": ["suite=integration"], "status": ["exited", "created"], "exited": ["0"], "health": ["unhealthy"]}));
+    assert!(!filters.is_empty());
+    assert!(ContainerFilters::all().is_empty());
+    assert_eq!(ContainerFilters::all().to_json().unwrap(), "{}");
+
+    // Empty filters are rejected before any container is listed
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    for report in [
+        client.containers.stop_all(&ContainerFilters::default(), None, 1),
+        client.containers.restart_all(&ContainerFilters::default(), None, 1),
+        client.containers.remove_all(&ContainerFilters::default(), false, true, 1),
+    ] {
+        match report {
+            Ok(_) => panic!("empty filters were accepted"),
+            Err(e) => assert_eq!(e.downcast_ref::<std::io::Error>().unwrap().kind(), std::io::ErrorKind::InvalidInput),
+        }
+    }
+}
+
+#[test]
+fn update_container_round_trip() {
+    assert_eq!(serde_json::to_string(&UpdateContainerWith::default()).unwrap(), "{}");
+
+    let update = UpdateContainerWith {
+        nano_cpus: Some(1_500_000_000),
+        pids_limit: Some(100),
+        oom_kill_disable: Some(false),
+        restart_policy: Some(RestartPolicy::OnFailure(3)),
+        blkio_device_read_bps: Some(vec![ThrottleDeviceRate { path: Some("/dev/sda".to_string()), rate: Some(1024) }]),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_value(&update).unwrap();
+    assert_eq!(json, serde_json::json!({
+        "NanoCpus": 1_500_000_000i64,
+        "PidsLimit": 100,
+        "OomKillDisable": false,
+        "RestartPolicy": {"Name": "on-failure", "MaximumRetryCount": 3},
+        "BlkioDeviceReadBps": [{"Path": "/dev/sda", "Rate": 1024}],
+    }));
+
+    let parsed: UpdateContainerWith = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(parsed.nano_cpus, Some(1_500_000_000));
+    assert_eq!(parsed.restart_policy, Some(RestartPolicy::OnFailure(3)));
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+}
+
+#[test]
+fn create_start_stop_all_remove_all() {
+    let mut client = Client::new("/var/run/docker.sock".to_string());
+    let run = std::process::id().to_string();
+    let options = CreateContainerFrom {
+        image: Some("alpine:latest".to_string()),
+        cmd: Some(vec!["sleep".to_string(), "30".to_string()]),
+        labels: Some([("docker-engine-api-bulk".to_string(), run.clone())].into_iter().collect()),
+        ..Default::default()
+    };
+
+    for name in ["test12", "test13", "test14"] {
+        let response = match client.containers.create_container(name, "linux", &options) {
+            Ok(response) => response,
+            Err(e) => panic!("Error: {}", e)
+        };
+
+        match client.containers.start_container(&response.id) {
+            Ok(_) => {},
+            Err(e) => panic!("Error: {}", e)
+        };
+    }
+
+    let filters = ContainerFilters { labels: vec![format!("docker-engine-api-bulk={}", run)], ..Default::default() };
+    let stopped = match client.containers.stop_all(&filters, Some(0), 2) {
+        Ok(report) => report,
+        Err(e) => panic!("Error: {}", e)
+    };
+    assert_eq!(stopped.succeeded().len(), 3);
+
+    let removed = match client.containers.remove_all(&filters, false, true, 2) {
+        Ok(report) => report,
+        Err(e) => panic!("Error: {}", e)
+    };
+    assert!(removed.is_success());
+    assert_eq!(removed.results.len(), 3);
+}
@@ -0,0 +1,25 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+/// The value the Engine reports for a time that never happened, such as `FinishedAt` of a running container.
+pub const ZERO_TIME: &str = "0001-01-01T00:00:00Z";
+
+/// Parses an RFC 3339 timestamp, returns `None` when it is empty or the zero value of the Engine.
+#[cfg(feature = "chrono")]
+pub fn parse(value: &str) -> Result<Option<DateTime<Utc>>, Box<dyn std::error::Error + Send + Sync>> {
+    if value.is_empty() || value.starts_with("0001-01-01T00:00:00") {
+        return Ok(None);
+    }
+
+    Ok(Some(DateTime::parse_from_rfc3339(value)?.with_timezone(&Utc)))
+}
+
+/// Converts a number of seconds since the epoch, returns `None` when it is zero or out of range.
+#[cfg(feature = "chrono")]
+pub fn from_unix(seconds: u64) -> Option<DateTime<Utc>> {
+    if seconds == 0 {
+        return None;
+    }
+
+    DateTime::from_timestamp(i64::try_from(seconds).ok()?, 0)
+}
@@ -0,0 +1,87 @@
+use crate::error::invalid;
+
+const UNITS: [(char, i64); 5] = [
+    ('k', 1 << 10),
+    ('m', 1 << 20),
+    ('g', 1 << 30),
+    ('t', 1 << 40),
+    ('p', 1 << 50),
+];
+
+/// Parses a size in Docker CLI notation into bytes.
+///
+/// Accepts a number of bytes optionally followed by a binary unit: `b`, `k`, `m`, `g`, `t` or `p`,
+/// case insensitive and optionally suffixed by `b` or `ib` (`512m`, `1.5GiB`, `100kb`).
+pub fn parse_bytes(value: &str) -> Result<i64, Box<dyn std::error::Error + Send + Sync>> {
+    let lower = value.trim().to_lowercase();
+    let number_end = lower.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(lower.len());
+    let (number, unit) = lower.split_at(number_end);
+    let unit = unit.trim_start();
+
+    let number: f64 = match number.parse() {
+        Ok(number) => number,
+        Err(_) => return Err(invalid(format!("invalid size: '{}'", value))),
+    };
+
+    let unit = unit.strip_suffix("ib").or_else(|| unit.strip_suffix('b')).unwrap_or(unit);
+    let multiplier = match unit.chars().next() {
+        None => 1,
+        Some(prefix) if unit.len() == 1 => match UNITS.iter().find(|(p, _)| *p == prefix) {
+            Some((_, multiplier)) => *multiplier,
+            None => return Err(invalid(format!("invalid size: '{}'", value))),
+        },
+        Some(_) => return Err(invalid(format!("invalid size: '{}'", value))),
+    };
+
+    let bytes = number * multiplier as f64;
+    if bytes > i64::MAX as f64 {
+        return Err(invalid(format!("size too large: '{}'", value)));
+    }
+
+    Ok(bytes as i64)
+}
+
+/// Parses a number of CPUs in Docker CLI notation (`1.5`, `0.25`) into units of 10^-9 CPUs.
+pub fn parse_cpus(value: &str) -> Result<i64, Box<dyn std::error::Error + Send + Sync>> {
+    match value.trim().parse::<f64>() {
+        Ok(cpus) => nano_cpus(cpus),
+        Err(_) => Err(invalid(format!("invalid number of CPUs: '{}'", value))),
+    }
+}
+
+/// Converts a number of CPUs into units of 10^-9 CPUs, the number must be greater than 0.
+pub fn nano_cpus(cpus: f64) -> Result<i64, Box<dyn std::error::Error + Send + Sync>> {
+    if !cpus.is_finite() || cpus <= 0.0 {
+        return Err(invalid(format!("the number of CPUs must be greater than 0: '{}'", cpus)));
+    }
+
+    let nano_cpus = (cpus * 1e9).round();
+    if nano_cpus > i64::MAX as f64 {
+        return Err(invalid(format!("too many CPUs: '{}'", cpus)));
+    }
+
+    Ok(nano_cpus as i64)
+}
+
+// The kernel supports at most 8192 CPUs, which also bounds the memory nodes
+const MAX_CPUSET_ID: u32 = 8191;
+
+/// Parses a cpuset in Docker CLI notation, a comma separated list of ids and ranges (`0-3`, `0,2`, `0-1,4`).
+///
+/// Returns the ids in ascending order, without duplicates.
+pub fn parse_cpuset(value: &str) -> Result<Vec<u32>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut ids = vec![];
+    for part in value.trim().split(',') {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        let (start, end) = match (start.trim().parse::<u32>(), end.trim().parse::<u32>()) {
+            (Ok(start), Ok(end)) if start <= end && end <= MAX_CPUSET_ID => (start, end),
+            _ => return Err(invalid(format!("invalid cpuset: '{}'", value))),
+        };
+
+        ids.extend(start..=end);
+    }
+
+    ids.sort_unstable();
+    ids.dedup();
+    Ok(ids)
+}
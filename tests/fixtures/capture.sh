@@ -0,0 +1,60 @@
+#!/bin/sh
+# Captures tests/fixtures/inspect_api_<version>.json from the Engine behind /var/run/docker.sock.
+#
+# Run it once per version against a daemon serving that API version natively
+# (Docker 20.10 for 1.41, 24.0 for 1.43, 27.x for 1.47):
+#
+#     tests/fixtures/capture.sh 1.43
+#
+# Each version captures the container the tests expect: an exited one for 1.41,
+# a healthy web server for 1.43 and a paused, unhealthy database for 1.47.
+# The output is written as the Engine returns it, do not edit it afterwards.
+set -eu
+
+version="$1"
+socket="${DOCKER_SOCKET:-/var/run/docker.sock}"
+fixture="$(dirname "$0")/inspect_api_${version}.json"
+
+inspect() {
+    curl --silent --fail --unix-socket "$socket" "http://localhost/v${version}/containers/$1/json${2:-}" > "$fixture"
+}
+
+case "$version" in
+1.41)
+    docker run --name sleepy_hopper --restart no alpine:latest /bin/sh -c 'exit 9' || true
+    inspect sleepy_hopper '?size=true'
+    docker rm sleepy_hopper
+    ;;
+1.43)
+    docker network create --subnet 172.28.0.0/16 frontend
+    docker run -d --name web --network frontend --ip 172.28.0.10 \
+        --restart on-failure:5 --memory 512m --stop-timeout 20 \
+        --publish 8080:80 --expose 443 \
+        --health-cmd 'wget -q -O- http://localhost/health || exit 1' \
+        --health-interval 30s --health-timeout 5s --health-start-period 10s --health-retries 3 \
+        nginx:1.25-alpine
+    docker exec -d web sleep 600
+    # Two health checks, 30s apart
+    sleep 70
+    inspect web
+    docker rm -f web
+    docker network rm frontend
+    ;;
+1.47)
+    docker network create backend
+    docker run -d --name db --network backend --network-alias db \
+        --annotation io.example.owner=data -e POSTGRES_PASSWORD=postgres \
+        --health-cmd 'sleep 10' --health-interval 10s --health-timeout 3s --health-retries 5 \
+        postgres:16
+    # Health checks timing out for about four intervals, then pause the container
+    sleep 45
+    docker pause db
+    inspect db
+    docker rm -f db
+    docker network rm backend
+    ;;
+*)
+    echo "usage: $0 1.41|1.43|1.47" >&2
+    exit 1
+    ;;
+esac